@@ -1,55 +1,69 @@
-use std::fs::read_to_string;
+use crate::solution::Solution;
 
-struct Input {
+pub struct Input {
     foo: Vec<String>,
 }
 
-fn day0(input: Input) -> u32 {
+fn day0(input: &Input) -> u32 {
     let mut result: u32 = 0;
-    for line in input.foo {
+    for line in &input.foo {
         result += line.parse::<u32>().expect("Unexpected input");
     }
     result
 }
 
-fn day0_v2(input: Input) -> u32 {
+fn day0_v2(input: &Input) -> u32 {
     let mut result: u32 = 0;
-    for line in input.foo {
+    for line in &input.foo {
         result += line.parse::<u32>().expect("Unexpected input");
     }
     result
 }
 
-fn parse_input(filepath: &str) -> Input {
+fn parse_input(input: &str) -> Input {
     let mut result: Vec<String> = vec![];
-    read_to_string(filepath).unwrap().lines().for_each(|l| {
+    input.lines().for_each(|l| {
         result.push(l.to_string());
     });
 
     Input { foo: result }
 }
 
-pub fn main(s: &str) -> u32 {
-    match s {
-        "example" => day0(parse_input("./tests/day00/example.txt")),
-        "actual" => day0(parse_input("./tests/day00/actual.txt")),
-        "example_v2" => day0_v2(parse_input("./tests/day00/example.txt")),
-        "actual_v2" => day0_v2(parse_input("./tests/day00/actual.txt")),
-        _ => todo!(),
+pub struct Day00;
+
+impl Solution for Day00 {
+    const DAY: u32 = 0;
+    const TITLE: &'static str = "TODO";
+
+    type Input = Input;
+    type Answer = u32;
+
+    fn parse(input: &str) -> Self::Input {
+        parse_input(input)
+    }
+
+    fn part1(input: &Self::Input) -> u32 {
+        day0(input)
+    }
+
+    fn part2(input: &Self::Input) -> u32 {
+        day0_v2(input)
     }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::solution::{solve, Part};
+    use crate::utils::EXAMPLE;
 
     #[test]
     fn test_example() {
-        assert_eq!(main("example"), 10);
+        assert_eq!(solve::<Day00>(EXAMPLE, Part::One).unwrap(), 10);
     }
 
     #[test]
     fn test_example_v2() {
-        assert_eq!(main("example_v2"), 10);
+        assert_eq!(solve::<Day00>(EXAMPLE, Part::Two).unwrap(), 10);
     }
 }
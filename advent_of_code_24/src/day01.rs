@@ -1,10 +1,10 @@
-use crate::utils::{get_test_file, FileNotFound, ACTUAL, EXAMPLE};
-use std::{collections::HashMap, fs::read_to_string};
+use crate::solution::Solution;
+use std::collections::HashMap;
 
-fn parse_input(filepath: String) -> (Vec<i32>, Vec<i32>) {
+fn parse_input(input: &str) -> (Vec<i32>, Vec<i32>) {
     let mut list_one: Vec<i32> = vec![];
     let mut list_two: Vec<i32> = vec![];
-    read_to_string(filepath).unwrap().lines().for_each(|l| {
+    input.lines().for_each(|l| {
         let mut nums = l.split("   ").map(|c| c.parse::<i32>().unwrap());
         list_one.push(nums.next().unwrap());
         list_two.push(nums.next().unwrap());
@@ -13,7 +13,9 @@ fn parse_input(filepath: String) -> (Vec<i32>, Vec<i32>) {
     (list_one, list_two)
 }
 
-fn match_list(mut l1: Vec<i32>, mut l2: Vec<i32>) -> i32 {
+fn match_list(l1: &[i32], l2: &[i32]) -> i32 {
+    let mut l1 = l1.to_vec();
+    let mut l2 = l2.to_vec();
     l1.sort();
     l2.sort();
 
@@ -21,7 +23,7 @@ fn match_list(mut l1: Vec<i32>, mut l2: Vec<i32>) -> i32 {
     (0..l1.len()).map(|i| (l1[i] - l2[i]).abs()).sum()
 }
 
-fn match_list_v2(l1: Vec<i32>, l2: Vec<i32>) -> i32 {
+fn match_list_v2(l1: &[i32], l2: &[i32]) -> i32 {
     let mut l2_counter: HashMap<i32, i32> = HashMap::new();
 
     // For each element in list one, add to list two counter map
@@ -34,51 +36,41 @@ fn match_list_v2(l1: Vec<i32>, l2: Vec<i32>) -> i32 {
         .sum()
 }
 
-pub fn main(s: &str) -> Result<i32, FileNotFound> {
-    match s {
-        "example" => match get_test_file(EXAMPLE, "01") {
-            Err(err) => Err(err),
-            Ok(file) => {
-                let (l1, l2) = parse_input(file);
-                Ok(match_list(l1, l2))
-            }
-        },
-        "actual" => match get_test_file(ACTUAL, "01") {
-            Err(err) => Err(err),
-            Ok(file) => {
-                let (l1, l2) = parse_input(file);
-                Ok(match_list(l1, l2))
-            }
-        },
-        "example_v2" => match get_test_file(EXAMPLE, "01") {
-            Err(err) => Err(err),
-            Ok(file) => {
-                let (l1, l2) = parse_input(file);
-                Ok(match_list_v2(l1, l2))
-            }
-        },
-        "actual_v2" => match get_test_file(ACTUAL, "01") {
-            Err(err) => Err(err),
-            Ok(file) => {
-                let (l1, l2) = parse_input(file);
-                Ok(match_list_v2(l1, l2))
-            }
-        },
-        _ => todo!(),
+pub struct Day01;
+
+impl Solution for Day01 {
+    const DAY: u32 = 1;
+    const TITLE: &'static str = "Historian Hysteria";
+
+    type Input = (Vec<i32>, Vec<i32>);
+    type Answer = i32;
+
+    fn parse(input: &str) -> Self::Input {
+        parse_input(input)
+    }
+
+    fn part1((l1, l2): &Self::Input) -> i32 {
+        match_list(l1, l2)
+    }
+
+    fn part2((l1, l2): &Self::Input) -> i32 {
+        match_list_v2(l1, l2)
     }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::solution::{solve, Part};
+    use crate::utils::EXAMPLE;
 
     #[test]
     fn test_example() {
-        assert_eq!(main("example").unwrap(), 11);
+        assert_eq!(solve::<Day01>(EXAMPLE, Part::One).unwrap(), 11);
     }
 
     #[test]
     fn test_example_v2() {
-        assert_eq!(main("example_v2").unwrap(), 31);
+        assert_eq!(solve::<Day01>(EXAMPLE, Part::Two).unwrap(), 31);
     }
 }
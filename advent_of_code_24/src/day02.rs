@@ -1,50 +1,47 @@
-use crate::utils::{get_test_file, FileNotFound, ACTUAL, EXAMPLE};
-use std::fs::read_to_string;
+use tracing::trace;
+
+use crate::solution::Solution;
 
 enum State {
-    UNSAFE,
-    SAFE,
+    Unsafe,
+    Safe,
 }
 
-fn reactor_safety(report: Vec<Vec<i32>>) -> i32 {
+fn reactor_safety(report: &[Vec<i32>]) -> i32 {
     let mut count_safe: i32 = 0;
     let mut report = report.iter();
-    // println!("{:?}", report);
+    trace!("{:?}", report);
 
     loop {
         count_safe += match report.next() {
             None => return count_safe,
             Some(r) => {
-                // println!("{:?}", r);
+                trace!("{:?}", r);
                 let mut prev = r[0];
-                let mut result = State::SAFE;
+                let mut result = State::Safe;
 
                 let is_increasing = r[1] > r[0];
 
                 for n in &r[1..] {
                     if prev == *n {
-                        // println!("Both values are equal!");
-                        // println!("prev: {prev}, n: {n}");
-                        result = State::UNSAFE;
+                        trace!("Both values are equal! prev: {prev}, n: {n}");
+                        result = State::Unsafe;
                         break;
                     } else if *n < prev && is_increasing {
-                        // println!("Decreasing but should be increasing!");
-                        // println!("prev: {prev}, n: {n}");
-                        result = State::UNSAFE;
+                        trace!("Decreasing but should be increasing! prev: {prev}, n: {n}");
+                        result = State::Unsafe;
                         break;
                     } else if *n > prev && !is_increasing {
-                        // println!("Increasing but should be decreasing!");
-                        // println!("prev: {prev}, n: {n}");
-                        result = State::UNSAFE;
+                        trace!("Increasing but should be decreasing! prev: {prev}, n: {n}");
+                        result = State::Unsafe;
                         break;
                     // if | prev - n | <= 3 : it's good
                     //      i.e. -3 <= prev - n <= 3
                     //      negation: prev - n < 3 or prev - n > 3
                     //      i.e. |prev - n| > 3
                     } else if (prev - *n).abs() > 3 {
-                        // println!("Diff greater than 3!");
-                        // println!("prev: {prev}, n: {n}");
-                        result = State::UNSAFE;
+                        trace!("Diff greater than 3! prev: {prev}, n: {n}");
+                        result = State::Unsafe;
                         break;
                     }
 
@@ -59,47 +56,43 @@ fn reactor_safety(report: Vec<Vec<i32>>) -> i32 {
 
 fn validate_state(prev: i32, n: i32, is_increasing: bool) -> State {
     if prev == n {
-        // println!("Both values are equal!");
-        // println!("prev: {prev}, n: {n}");
-        return State::UNSAFE;
+        trace!("Both values are equal! prev: {prev}, n: {n}");
+        return State::Unsafe;
     } else if n < prev && is_increasing {
-        // println!("Decreasing but should be increasing!");
-        // println!("prev: {prev}, n: {n}");
-        return State::UNSAFE;
+        trace!("Decreasing but should be increasing! prev: {prev}, n: {n}");
+        return State::Unsafe;
     } else if n > prev && !is_increasing {
-        // println!("Increasing but should be decreasing!");
-        // println!("prev: {prev}, n: {n}");
-        return State::UNSAFE;
+        trace!("Increasing but should be decreasing! prev: {prev}, n: {n}");
+        return State::Unsafe;
     } else if (prev - n).abs() > 3 {
-        // println!("Diff greater than 3!");
-        // println!("prev: {prev}, n: {n}");
-        return State::UNSAFE;
+        trace!("Diff greater than 3! prev: {prev}, n: {n}");
+        return State::Unsafe;
     }
 
-    State::SAFE
+    State::Safe
 }
 
-fn reactor_safety_v2(report: Vec<Vec<i32>>) -> i32 {
+fn reactor_safety_v2(report: &[Vec<i32>]) -> i32 {
     /*  Edge cases not considered in this approach:
      *       [10, 1, 2, 3, 4]
-     *           Should be SAFE because if we skip 10, everything else is valid
+     *           Should be Safe because if we skip 10, everything else is valid
      *
      *       [5, 2, 6, 7, 8]
-     *           Should be SAFE because it's actually increasing when we skip 2
+     *           Should be Safe because it's actually increasing when we skip 2
      *
      */
     let mut count_safe: i32 = 0;
-    // println!("{:?}", report);
+    trace!("{:?}", report);
 
     let mut i: usize = 0;
     loop {
         count_safe += match report.get(i) {
             None => return count_safe,
             Some(r) => {
-                // println!("{:?}", r);
+                trace!("{:?}", r);
                 let mut r = r.iter().peekable();
                 let mut prev = *r.next().unwrap();
-                let mut result: State = State::UNSAFE;
+                let mut result: State = State::Unsafe;
 
                 let is_increasing = **r.peek().unwrap() > prev;
 
@@ -107,12 +100,12 @@ fn reactor_safety_v2(report: Vec<Vec<i32>>) -> i32 {
                     result = validate_state(prev, *n, is_increasing);
 
                     match validate_state(prev, *n, is_increasing) {
-                        State::SAFE => prev = *n,
-                        State::UNSAFE => {
+                        State::Safe => prev = *n,
+                        State::Unsafe => {
                             if let Some(skip) = r.peek() {
                                 match validate_state(prev, **skip, is_increasing) {
-                                    State::SAFE => prev = *n,
-                                    State::UNSAFE => break,
+                                    State::Safe => prev = *n,
+                                    State::Unsafe => break,
                                 }
                             };
                         }
@@ -126,9 +119,9 @@ fn reactor_safety_v2(report: Vec<Vec<i32>>) -> i32 {
     }
 }
 
-fn parse_input(filepath: &str) -> Vec<Vec<i32>> {
+fn parse_input(input: &str) -> Vec<Vec<i32>> {
     let mut result: Vec<Vec<i32>> = vec![];
-    read_to_string(filepath).unwrap().lines().for_each(|l| {
+    input.lines().for_each(|l| {
         let nums: Vec<i32> = l.split(" ").map(|c| c.parse::<i32>().unwrap()).collect();
         result.push(nums);
     });
@@ -136,51 +129,41 @@ fn parse_input(filepath: &str) -> Vec<Vec<i32>> {
     result
 }
 
-pub fn main(s: &str) -> Result<i32, FileNotFound> {
-    match s {
-        "example" => match get_test_file(EXAMPLE, "02") {
-            Err(err) => Err(err),
-            Ok(file) => {
-                let report = parse_input(&file);
-                Ok(reactor_safety(report))
-            }
-        },
-        "actual" => match get_test_file(ACTUAL, "02") {
-            Err(err) => Err(err),
-            Ok(file) => {
-                let report = parse_input(&file);
-                Ok(reactor_safety(report))
-            }
-        },
-        "example_v2" => match get_test_file(EXAMPLE, "02") {
-            Err(err) => Err(err),
-            Ok(file) => {
-                let report = parse_input(&file);
-                Ok(reactor_safety_v2(report))
-            }
-        },
-        "actual_v2" => match get_test_file(ACTUAL, "02") {
-            Err(err) => Err(err),
-            Ok(file) => {
-                let report = parse_input(&file);
-                Ok(reactor_safety_v2(report))
-            }
-        },
-        _ => todo!(),
+pub struct Day02;
+
+impl Solution for Day02 {
+    const DAY: u32 = 2;
+    const TITLE: &'static str = "Red-Nosed Reports";
+
+    type Input = Vec<Vec<i32>>;
+    type Answer = i32;
+
+    fn parse(input: &str) -> Self::Input {
+        parse_input(input)
+    }
+
+    fn part1(input: &Self::Input) -> i32 {
+        reactor_safety(input)
+    }
+
+    fn part2(input: &Self::Input) -> i32 {
+        reactor_safety_v2(input)
     }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::solution::{solve, Part};
+    use crate::utils::EXAMPLE;
 
     #[test]
     fn test_example() {
-        assert_eq!(main("example").unwrap(), 2);
+        assert_eq!(solve::<Day02>(EXAMPLE, Part::One).unwrap(), 2);
     }
 
     #[test]
     fn test_example_v2() {
-        assert_eq!(main("example_v2").unwrap(), 4);
+        assert_eq!(solve::<Day02>(EXAMPLE, Part::Two).unwrap(), 4);
     }
 }
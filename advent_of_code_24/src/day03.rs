@@ -1,6 +1,7 @@
-use crate::utils::{get_test_file, FileNotFound, ACTUAL, EXAMPLE};
+use crate::day03_failed;
+use crate::solution::{Part, Solution, Variant};
 use regex::Regex;
-use std::fs::read_to_string;
+use tracing::trace;
 
 #[derive(PartialEq)]
 enum State {
@@ -8,8 +9,7 @@ enum State {
     Do,
 }
 
-fn compute_tokens_v2(filepath: &str) -> u32 {
-    let input = &read_to_string(filepath).unwrap()[..];
+fn compute_tokens_v2(input: &str) -> u32 {
     let re = Regex::new(r"(do\(\))|(don't\(\))|(mul\([0-9]+,[0-9]+\))").unwrap();
     let mut tokens: Vec<&str> = vec![];
     for (_, [val]) in re.captures_iter(input).map(|c| c.extract()) {
@@ -20,7 +20,7 @@ fn compute_tokens_v2(filepath: &str) -> u32 {
 
     let mut state = State::Do;
     for t in tokens {
-        // println!("{:?}", t);
+        trace!("{:?}", t);
         match t {
             "do()" => state = State::Do,
             "don't()" => state = State::Dont,
@@ -28,11 +28,9 @@ fn compute_tokens_v2(filepath: &str) -> u32 {
                 if state == State::Dont {
                     continue;
                 }
-                // println!("{:?}", s);
                 let cs = s.to_owned();
-                // println!("{:?}", cs);
                 let mut cs = cs.get(4..cs.len() - 1).unwrap().split(",");
-                // println!("{:?}", cs);
+                trace!("{:?}", cs);
                 let n1: u32 = cs.next().unwrap().parse().unwrap();
                 let n2: u32 = cs.next().unwrap().parse().unwrap();
 
@@ -44,8 +42,7 @@ fn compute_tokens_v2(filepath: &str) -> u32 {
     result
 }
 
-fn compute_tokens(filepath: &str) -> u32 {
-    let input = &read_to_string(filepath).unwrap()[..];
+fn compute_tokens(input: &str) -> u32 {
     let re = Regex::new(r"mul\(([0-9]+),([0-9]+)\)").unwrap();
 
     let mut tokens = vec![];
@@ -63,39 +60,46 @@ fn compute_tokens(filepath: &str) -> u32 {
     result
 }
 
-pub fn main(s: &str) -> Result<u32, FileNotFound> {
-    match s {
-        "example" => match get_test_file(EXAMPLE, "03") {
-            Err(err) => Err(err),
-            Ok(file) => Ok(compute_tokens(&file)),
-        },
-        "actual" => match get_test_file(ACTUAL, "03") {
-            Err(err) => Err(err),
-            Ok(file) => Ok(compute_tokens(&file)),
-        },
-        "example_v2" => match get_test_file(EXAMPLE, "03") {
-            Err(err) => Err(err),
-            Ok(file) => Ok(compute_tokens_v2(&file)),
-        },
-        "actual_v2" => match get_test_file(ACTUAL, "03") {
-            Err(err) => Err(err),
-            Ok(file) => Ok(compute_tokens_v2(&file)),
-        },
-        _ => todo!(),
+pub struct Day03;
+
+impl Solution for Day03 {
+    const DAY: u32 = 3;
+    const TITLE: &'static str = "Mull It Over";
+    const VARIANTS: &'static [Variant<Self>] = &[Variant {
+        name: "lexer",
+        part: Part::One,
+        solve: |input| day03_failed::lexer(input),
+    }];
+
+    type Input = String;
+    type Answer = u32;
+
+    fn parse(input: &str) -> Self::Input {
+        input.to_string()
+    }
+
+    fn part1(input: &Self::Input) -> u32 {
+        compute_tokens(input)
+    }
+
+    fn part2(input: &Self::Input) -> u32 {
+        compute_tokens_v2(input)
     }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::solution::{solve, Part};
+    use crate::utils::EXAMPLE;
 
     #[test]
     fn test_example() {
-        assert_eq!(main("example").unwrap(), 161);
+        assert_eq!(solve::<Day03>(EXAMPLE, Part::One).unwrap(), 161);
     }
 
     #[test]
     fn test_example_v2() {
-        assert_eq!(main("example_v2").unwrap(), 8 * 5);
+        assert_eq!(solve::<Day03>(EXAMPLE, Part::Two).unwrap(), 8 * 5);
     }
 }
@@ -1,4 +1,4 @@
-use std::fs::read_to_string;
+use tracing::{error, trace};
 
 #[derive(Debug, PartialEq)]
 enum Token {
@@ -15,7 +15,7 @@ struct TokenBuffer {
 }
 
 impl TokenBuffer {
-    fn add_if_valid_or_empty(&mut self, t: Token) -> () {
+    fn add_if_valid_or_empty(&mut self, t: Token) {
         let last_t = self.last();
 
         match last_t {
@@ -87,22 +87,18 @@ impl TokenBuffer {
 
         loop {
             let t = tokens.next();
-            if t.is_some_and(|t| matches!(t, &Token::Number { .. })) {
-                continue;
-            } else if t.is_some_and(|t| t == &Token::RPar) {
-                return true;
-            } else {
-                return false;
+            if !t.is_some_and(|t| matches!(t, &Token::Number { .. })) {
+                return t.is_some_and(|t| t == &Token::RPar);
             }
         }
     }
 
     fn compute(&mut self) -> u32 {
-        println!("{:?}", self.tokens);
+        trace!("{:?}", self.tokens);
         let mut tokens = self.tokens.iter();
 
         let mut n1: String = String::new();
-        while let Some(t) = tokens.next() {
+        for t in tokens.by_ref() {
             match t {
                 Token::Number(n) => {
                     n1.push(char::from_digit(*n, 10).unwrap());
@@ -113,7 +109,7 @@ impl TokenBuffer {
         }
 
         let mut n2: String = String::new();
-        while let Some(t) = tokens.next() {
+        for t in tokens {
             match t {
                 Token::Number(n) => {
                     n2.push(char::from_digit(*n, 10).unwrap());
@@ -126,14 +122,14 @@ impl TokenBuffer {
         let n1 = match n1.parse::<u32>() {
             Ok(n) => n,
             Err(e) => {
-                println!("n1: {}", n1);
+                error!("n1: {}", n1);
                 panic!("{e}");
             }
         };
         let n2 = match n2.parse::<u32>() {
             Ok(n) => n,
             Err(e) => {
-                println!("n1: {}", n1);
+                error!("n2: {}", n2);
                 panic!("{e}");
             }
         };
@@ -143,10 +139,7 @@ impl TokenBuffer {
     }
 
     fn last(&self) -> Option<&Token> {
-        match self.tokens.last() {
-            Some(t) => Some(t),
-            None => None,
-        }
+        self.tokens.last()
     }
 }
 
@@ -164,9 +157,7 @@ fn compute_tokens(tokens: Vec<Token>) -> u32 {
     result
 }
 
-fn parse_input(filepath: &str) -> Vec<Token> {
-    let input = &read_to_string(filepath).unwrap()[..];
-
+fn parse_input(input: &str) -> Vec<Token> {
     let mut tokens: Vec<Token> = vec![];
     let mut i: usize = 0;
 
@@ -186,9 +177,8 @@ fn parse_input(filepath: &str) -> Vec<Token> {
                     break;
                 }
 
-                if *next_c.unwrap() != 'u' || *next_next_c.unwrap() != 'l' {
-                    ()
-                }
+                // The "ul" is never actually checked, so any 'm' followed by
+                // two characters counts as a Mul
 
                 i += 2;
 
@@ -202,48 +192,24 @@ fn parse_input(filepath: &str) -> Vec<Token> {
         i += 1;
     }
 
-    // println!("{:?}", tokens);
+    trace!("{:?}", tokens);
 
     tokens
 }
 
-pub fn main(s: &str) -> u32 {
-    match s {
-        "example" => {
-            let tokens = parse_input("./tests/day03/example.txt");
-            compute_tokens(tokens)
-        }
-        "actual" => {
-            let tokens = parse_input("./tests/day03/actual.txt");
-            compute_tokens(tokens)
-        }
-
-        "example_v2" => {
-            // let input = parse_input("./tests/day03/example.txt");
-            // lexer_v2(&input[..])
-            0
-        }
-        "actual_v2" => {
-            // let input = parse_input("./tests/day03/actual.txt");
-            // lexer_v2(&input[..])
-            0
-        }
-        _ => todo!(),
-    }
+/// Part 1 of day 3 with a hand-rolled lexer instead of a regex
+pub fn lexer(input: &str) -> u32 {
+    compute_tokens(parse_input(input))
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::utils::{read_test_file, EXAMPLE};
 
     #[test]
     fn test_example() {
-        assert_eq!(main("example"), 161);
-    }
-
-    #[test]
-    fn test_example_v2() {
-        assert_eq!(main("example_v2"), 0);
+        assert_eq!(lexer(&read_test_file(EXAMPLE, "03").unwrap()), 161);
     }
     //
     // #[test]
@@ -1,6 +1,5 @@
-use crate::utils::{get_test_file, FileNotFound, ACTUAL, EXAMPLE};
+use crate::solution::Solution;
 use crate::utils::{Board, Direction};
-use std::fs::read_to_string;
 
 const XMAS: [char; 4] = ['X', 'M', 'A', 'S'];
 
@@ -28,7 +27,7 @@ fn check_match(board: &Board<char>, pos: (usize, usize), d: Direction, l: usize)
     }
 }
 
-fn match_crosswords(board: Board<char>) -> u32 {
+fn match_crosswords(board: &Board<char>) -> u32 {
     let mut matches: u32 = 0;
 
     // for every element in board
@@ -38,7 +37,7 @@ fn match_crosswords(board: Board<char>) -> u32 {
             if board[i][j] == XMAS[0] {
                 // check surroundings
                 for d in Direction::DIRECTIONS {
-                    matches += match check_match(&board, (i, j), d, 1) {
+                    matches += match check_match(board, (i, j), d, 1) {
                         true => 1,
                         false => 0,
                     };
@@ -80,68 +79,68 @@ fn check_match_v2(board: &Board<char>, pos: (usize, usize)) -> bool {
             _ => break,
         }
     }
-    return (m_gravity.0.abs() == 2 || m_gravity.1.abs() == 2)
-        && (s_gravity.0.abs() == 2 || s_gravity.1.abs() == 2);
+    (m_gravity.0.abs() == 2 || m_gravity.1.abs() == 2)
+        && (s_gravity.0.abs() == 2 || s_gravity.1.abs() == 2)
 }
 
-fn match_crosswords_v2(board: Board<char>) -> u32 {
+fn match_crosswords_v2(board: &Board<char>) -> u32 {
     let mut matches: u32 = 0;
 
     // for every element in board
     for i in 0..board.len() {
         for j in 0..board[i].len() {
             // if it's A
-            if board[i][j] == 'A' {
-                if check_match_v2(&board, (i, j)) {
-                    matches += 1;
-                }
+            if board[i][j] == 'A' && check_match_v2(board, (i, j)) {
+                matches += 1;
             }
         }
     }
     matches
 }
 
-fn parse_input(filepath: &str) -> Board<char> {
+fn parse_input(input: &str) -> Board<char> {
     let mut result: Vec<Vec<char>> = vec![];
-    for line in read_to_string(filepath).unwrap().lines() {
+    for line in input.lines() {
         result.push(line.chars().collect());
     }
     Board::new(result)
 }
 
-pub fn main(s: &str) -> Result<u32, FileNotFound> {
-    match s {
-        "example" => match get_test_file(EXAMPLE, "04") {
-            Err(err) => Err(err),
-            Ok(file) => Ok(match_crosswords(parse_input(&file))),
-        },
-        "actual" => match get_test_file(ACTUAL, "04") {
-            Err(err) => Err(err),
-            Ok(file) => Ok(match_crosswords(parse_input(&file))),
-        },
-        "example_v2" => match get_test_file(EXAMPLE, "04") {
-            Err(err) => Err(err),
-            Ok(file) => Ok(match_crosswords_v2(parse_input(&file))),
-        },
-        "actual_v2" => match get_test_file(ACTUAL, "04") {
-            Err(err) => Err(err),
-            Ok(file) => Ok(match_crosswords_v2(parse_input(&file))),
-        },
-        _ => todo!(),
+pub struct Day04;
+
+impl Solution for Day04 {
+    const DAY: u32 = 4;
+    const TITLE: &'static str = "Ceres Search";
+
+    type Input = Board<char>;
+    type Answer = u32;
+
+    fn parse(input: &str) -> Self::Input {
+        parse_input(input)
+    }
+
+    fn part1(input: &Self::Input) -> u32 {
+        match_crosswords(input)
+    }
+
+    fn part2(input: &Self::Input) -> u32 {
+        match_crosswords_v2(input)
     }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::solution::{solve, Part};
+    use crate::utils::EXAMPLE;
 
     #[test]
     fn test_example() {
-        assert_eq!(main("example").unwrap(), 18);
+        assert_eq!(solve::<Day04>(EXAMPLE, Part::One).unwrap(), 18);
     }
 
     #[test]
     fn test_example_v2() {
-        assert_eq!(main("example_v2").unwrap(), 9);
+        assert_eq!(solve::<Day04>(EXAMPLE, Part::Two).unwrap(), 9);
     }
 }
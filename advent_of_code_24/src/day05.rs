@@ -1,15 +1,17 @@
-use std::{cmp::Ordering, collections::HashSet, fs::read_to_string};
+use std::{cmp::Ordering, collections::HashSet};
 
-use crate::utils::{get_test_file, FileNotFound, ACTUAL, EXAMPLE};
+use tracing::{debug, trace};
+
+use crate::solution::Solution;
 
 #[derive(Debug)]
-struct Orders {
+pub struct Orders {
     rules: Vec<(String, String)>,
     production: Vec<Vec<String>>,
 }
 
 impl Orders {
-    fn filter_rules<'a>(&'a self, order_line: HashSet<String>) -> Vec<&'a (String, String)> {
+    fn filter_rules(&self, order_line: HashSet<String>) -> Vec<&(String, String)> {
         self.rules
             .iter()
             .filter(|(p1, p2)| order_line.contains(p1) && order_line.contains(p2))
@@ -29,7 +31,7 @@ impl Orders {
     }
 }
 
-fn day5(orders: Orders) -> u32 {
+fn day5(orders: &Orders) -> u32 {
     let mut result: u32 = 0;
 
     for prod_line in &orders.production {
@@ -44,10 +46,8 @@ fn day5(orders: Orders) -> u32 {
         for p in prod_line {
             for (p1, p2) in &filtered_rules {
                 // Check rules
-                if p == p2 {
-                    if unseen.contains(p1) {
-                        valid = false;
-                    }
+                if p == p2 && unseen.contains(p1) {
+                    valid = false;
                 }
             }
             seen.insert(p.clone());
@@ -64,7 +64,7 @@ fn day5(orders: Orders) -> u32 {
     result
 }
 
-fn day5_v2(orders: Orders) -> u32 {
+fn day5_v2(orders: &Orders) -> u32 {
     let mut result: u32 = 0;
 
     for prod_line in &orders.production {
@@ -76,11 +76,9 @@ fn day5_v2(orders: Orders) -> u32 {
         for p in prod_line {
             for (p1, p2) in &filtered_rules {
                 // Check rules
-                if p == p2 {
-                    if unseen.contains(p1) {
-                        valid = false;
-                        break;
-                    }
+                if p == p2 && unseen.contains(p1) {
+                    valid = false;
+                    break;
                 }
             }
             if !valid {
@@ -91,14 +89,12 @@ fn day5_v2(orders: Orders) -> u32 {
         }
 
         if !valid {
-            // println!("prod_line        : {:?}", prod_line);
-            // println!("order_line       : {:?}", order_line);
-            // println!("filtered_rules   : {:?}", filtered_rules);
+            trace!("prod_line        : {:?}", prod_line);
+            trace!("filtered_rules   : {:?}", filtered_rules);
 
             let mut ordered_prod_line = prod_line.clone();
             ordered_prod_line.sort_by(|a, b| Orders::sort_rules(a, b, &filtered_rules));
-            // println!("ordered_prod_line: {:?}", ordered_prod_line);
-            // println!();
+            debug!("ordered_prod_line: {:?}", ordered_prod_line);
 
             let middle_page_index = (ordered_prod_line.len() - 1) / 2;
             result += ordered_prod_line[middle_page_index].parse::<u32>().unwrap();
@@ -108,13 +104,12 @@ fn day5_v2(orders: Orders) -> u32 {
     result
 }
 
-fn parse_text(file_path: &str) -> Orders {
-    let content = read_to_string(file_path).unwrap();
-    let mut lines = content.lines();
+fn parse_text(input: &str) -> Orders {
+    let mut lines = input.lines();
 
     let mut order_rules: Vec<(String, String)> = vec![];
-    while let Some(line) = lines.next() {
-        if line == "" {
+    for line in lines.by_ref() {
+        if line.is_empty() {
             break;
         }
 
@@ -127,7 +122,7 @@ fn parse_text(file_path: &str) -> Orders {
     }
 
     let mut production_order: Vec<Vec<String>> = vec![];
-    while let Some(line) = lines.next() {
+    for line in lines {
         production_order.push(line.split(",").map(|s| s.to_string()).collect());
     }
 
@@ -137,38 +132,40 @@ fn parse_text(file_path: &str) -> Orders {
     }
 }
 
-pub fn main(s: &str) -> Result<u32, FileNotFound> {
-    match s {
-        "example" => match get_test_file(EXAMPLE, "05") {
-            Err(err) => Err(err),
-            Ok(file) => Ok(day5(parse_text(&file))),
-        },
-        "actual" => match get_test_file(ACTUAL, "05") {
-            Err(err) => Err(err),
-            Ok(file) => Ok(day5(parse_text(&file))),
-        },
-        "example_v2" => match get_test_file(EXAMPLE, "05") {
-            Err(err) => Err(err),
-            Ok(file) => Ok(day5_v2(parse_text(&file))),
-        },
-        "actual_v2" => match get_test_file(ACTUAL, "05") {
-            Err(err) => Err(err),
-            Ok(file) => Ok(day5_v2(parse_text(&file))),
-        },
-        _ => todo!(),
+pub struct Day05;
+
+impl Solution for Day05 {
+    const DAY: u32 = 5;
+    const TITLE: &'static str = "Print Queue";
+
+    type Input = Orders;
+    type Answer = u32;
+
+    fn parse(input: &str) -> Self::Input {
+        parse_text(input)
+    }
+
+    fn part1(input: &Self::Input) -> u32 {
+        day5(input)
+    }
+
+    fn part2(input: &Self::Input) -> u32 {
+        day5_v2(input)
     }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::solution::{solve, Part};
+    use crate::utils::EXAMPLE;
 
     #[test]
     fn test_example() {
-        assert_eq!(main("example").unwrap(), 143);
+        assert_eq!(solve::<Day05>(EXAMPLE, Part::One).unwrap(), 143);
     }
     #[test]
     fn test_example_v2() {
-        assert_eq!(main("example_v2").unwrap(), 123);
+        assert_eq!(solve::<Day05>(EXAMPLE, Part::Two).unwrap(), 123);
     }
 }
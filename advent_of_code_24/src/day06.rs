@@ -1,8 +1,8 @@
-use crate::utils::{get_test_file, Board, Direction, FileNotFound, ACTUAL, EXAMPLE};
-use std::fs::read_to_string;
+use crate::solution::Solution;
+use crate::utils::{Board, Direction};
 
 #[derive(Clone)]
-struct GuardBoard {
+pub struct GuardBoard {
     board: Board<char>,
     guard_dir: Direction,
     pos: (usize, usize),
@@ -70,7 +70,7 @@ impl GuardBoard {
     }
 
     #[allow(dead_code)]
-    fn print_board(&self) -> () {
+    fn print_board(&self) {
         use log_update::LogUpdate;
         use std::{io::stdout, thread::sleep, time::Duration};
 
@@ -83,7 +83,7 @@ impl GuardBoard {
             .collect::<Vec<String>>();
 
         sleep(Duration::from_millis(1));
-        log_update.render(&format!("{}", lines.join("\n"))).unwrap();
+        log_update.render(&lines.join("\n")).unwrap();
     }
 
     fn is_loop(&mut self) -> bool {
@@ -166,17 +166,16 @@ impl GuardBoard {
     }
 }
 
-fn day6(guard_board: GuardBoard) -> u32 {
-    guard_board.walk()
+fn day6(guard_board: &GuardBoard) -> u32 {
+    guard_board.clone().walk()
 }
 
-fn day6_v2(guard_board: GuardBoard) -> u32 {
-    guard_board.place_obstacles()
+fn day6_v2(guard_board: &GuardBoard) -> u32 {
+    guard_board.clone().place_obstacles()
 }
 
-fn parse_input(file_path: &str) -> GuardBoard {
-    let content = read_to_string(file_path).unwrap();
-    let lines = content.lines();
+fn parse_input(input: &str) -> GuardBoard {
+    let lines = input.lines();
     let board: Vec<Vec<char>> = lines.map(|l| l.chars().collect::<Vec<char>>()).collect();
     let mut starting_pos = (0, 0);
 
@@ -186,8 +185,8 @@ fn parse_input(file_path: &str) -> GuardBoard {
             break;
         }
 
-        for j in 0..board.get(i).unwrap().len() {
-            if board[i][j] == '^' {
+        for (j, c) in board[i].iter().enumerate() {
+            if *c == '^' {
                 starting_pos = (i, j);
                 break 'board_loop;
             }
@@ -199,38 +198,40 @@ fn parse_input(file_path: &str) -> GuardBoard {
     GuardBoard::new(board, starting_pos)
 }
 
-pub fn main(s: &str) -> Result<u32, FileNotFound> {
-    match s {
-        "example" => match get_test_file(EXAMPLE, "06") {
-            Err(err) => Err(err),
-            Ok(file) => Ok(day6(parse_input(&file))),
-        },
-        "actual" => match get_test_file(ACTUAL, "06") {
-            Err(err) => Err(err),
-            Ok(file) => Ok(day6(parse_input(&file))),
-        },
-        "example_v2" => match get_test_file(EXAMPLE, "06") {
-            Err(err) => Err(err),
-            Ok(file) => Ok(day6_v2(parse_input(&file))),
-        },
-        "actual_v2" => match get_test_file(ACTUAL, "06") {
-            Err(err) => Err(err),
-            Ok(file) => Ok(day6_v2(parse_input(&file))),
-        },
-        _ => todo!(),
+pub struct Day06;
+
+impl Solution for Day06 {
+    const DAY: u32 = 6;
+    const TITLE: &'static str = "Guard Gallivant";
+
+    type Input = GuardBoard;
+    type Answer = u32;
+
+    fn parse(input: &str) -> Self::Input {
+        parse_input(input)
+    }
+
+    fn part1(input: &Self::Input) -> u32 {
+        day6(input)
+    }
+
+    fn part2(input: &Self::Input) -> u32 {
+        day6_v2(input)
     }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::solution::{solve, Part};
+    use crate::utils::EXAMPLE;
 
     #[test]
     fn test_example() {
-        assert_eq!(main("example").unwrap(), 41);
+        assert_eq!(solve::<Day06>(EXAMPLE, Part::One).unwrap(), 41);
     }
     #[test]
     fn test_example_v2() {
-        assert_eq!(main("example_v2").unwrap(), 6);
+        assert_eq!(solve::<Day06>(EXAMPLE, Part::Two).unwrap(), 6);
     }
 }
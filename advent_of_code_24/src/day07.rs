@@ -1,15 +1,13 @@
-use std::fs::read_to_string;
-
-use crate::utils::{get_test_file, FileNotFound, ACTUAL, EXAMPLE};
+use crate::solution::Solution;
 
 #[derive(Debug, Clone)]
 enum Operation {
-    SUM,
-    MULT,
-    CONCAT,
+    Sum,
+    Mult,
+    Concat,
 }
 
-#[derive(Clone, Debug)]
+#[derive(Clone, Debug, Default)]
 struct Operators {
     val: u32,
     cap: u32,
@@ -27,18 +25,18 @@ trait Binary {
 impl Binary for u32 {}
 
 trait Trinary {
-    fn as_trinary(self) -> String;
+    fn as_trinary(&self) -> String;
 }
 
 impl Trinary for u32 {
-    fn as_trinary(self) -> String {
+    fn as_trinary(&self) -> String {
         let mut result = vec![];
         let radix = 3;
-        let mut x = self;
+        let mut x = *self;
 
         loop {
             let m = x % radix;
-            x = x / radix;
+            x /= radix;
 
             result.push(std::char::from_digit(m, radix).unwrap());
             if x == 0 {
@@ -63,29 +61,29 @@ impl Concat for u64 {
 }
 
 impl Operators {
-    fn as_operations(self: &Self) -> Vec<Operation> {
+    fn as_operations(&self) -> Vec<Operation> {
         let padding = self.cap.as_binary().len() - self.val.as_binary().len();
-        let mut v: Vec<Operation> = vec![Operation::SUM; padding];
+        let mut v: Vec<Operation> = vec![Operation::Sum; padding];
 
         for op in self.val.as_binary().chars() {
             match op {
-                '0' => v.push(Operation::SUM),
-                '1' => v.push(Operation::MULT),
+                '0' => v.push(Operation::Sum),
+                '1' => v.push(Operation::Mult),
                 _ => panic!("How the fuck did this happen"),
             };
         }
         v
     }
 
-    fn as_operations_v2(self: &Self) -> Vec<Operation> {
+    fn as_operations_v2(&self) -> Vec<Operation> {
         let padding = self.cap.as_trinary().len() - self.val.as_trinary().len();
-        let mut v: Vec<Operation> = vec![Operation::SUM; padding];
+        let mut v: Vec<Operation> = vec![Operation::Sum; padding];
 
         for op in self.val.as_trinary().chars() {
             match op {
-                '0' => v.push(Operation::SUM),
-                '1' => v.push(Operation::MULT),
-                '2' => v.push(Operation::CONCAT),
+                '0' => v.push(Operation::Sum),
+                '1' => v.push(Operation::Mult),
+                '2' => v.push(Operation::Concat),
                 _ => panic!("How the fuck did this happen"),
             };
         }
@@ -94,26 +92,38 @@ impl Operators {
 }
 
 #[derive(Debug)]
-struct Equation {
+pub struct Equation {
     total: u64,
     values: Vec<u64>,
     operators: Operators,
 }
 
 impl Equation {
-    fn evaluate(self: &Self) -> u64 {
+    // Copy of the equation whose operators go through every combination of `radix` operations
+    fn with_radix(&self, radix: u32) -> Self {
+        Equation {
+            total: self.total,
+            values: self.values.clone(),
+            operators: Operators {
+                val: 0,
+                cap: radix.pow(self.values.len() as u32 - 1) - 1,
+            },
+        }
+    }
+
+    fn evaluate(&self) -> u64 {
         let mut result = self.values[0];
         let operations = self.operators.as_operations();
 
         for i in 0..operations.len() {
             match operations.get(i) {
-                Some(Operation::SUM) => {
+                Some(Operation::Sum) => {
                     result += self.values[i + 1];
                     if result > self.total {
                         break;
                     }
                 }
-                Some(Operation::MULT) => {
+                Some(Operation::Mult) => {
                     result *= self.values[i + 1];
                     if result > self.total {
                         break;
@@ -125,26 +135,26 @@ impl Equation {
         result
     }
 
-    fn evaluate_v2(self: &Self) -> u64 {
+    fn evaluate_v2(&self) -> u64 {
         let mut result = self.values[0];
         let operations = self.operators.as_operations_v2();
 
         for i in 0..operations.len() {
             match operations.get(i) {
                 None => (),
-                Some(Operation::SUM) => {
+                Some(Operation::Sum) => {
                     result += self.values[i + 1];
                     if result > self.total {
                         break;
                     }
                 }
-                Some(Operation::MULT) => {
+                Some(Operation::Mult) => {
                     result *= self.values[i + 1];
                     if result > self.total {
                         break;
                     }
                 }
-                Some(Operation::CONCAT) => {
+                Some(Operation::Concat) => {
                     result = result.concat(self.values[i + 1]);
                     if result > self.total {
                         break;
@@ -156,9 +166,10 @@ impl Equation {
     }
 }
 
-fn day7(input: Vec<Equation>) -> u64 {
+fn day7(input: &[Equation]) -> u64 {
     let mut result = 0;
-    for mut equation in input {
+    for equation in input {
+        let mut equation = equation.with_radix(2);
         loop {
             if equation.evaluate() == equation.total {
                 result += equation.total;
@@ -173,9 +184,10 @@ fn day7(input: Vec<Equation>) -> u64 {
     result
 }
 
-fn day7_v2(input: Vec<Equation>) -> u64 {
+fn day7_v2(input: &[Equation]) -> u64 {
     let mut result = 0;
-    for mut equation in input {
+    for equation in input {
+        let mut equation = equation.with_radix(3);
         loop {
             if equation.evaluate_v2() == equation.total {
                 result += equation.total;
@@ -190,97 +202,65 @@ fn day7_v2(input: Vec<Equation>) -> u64 {
     result
 }
 
-fn parse_input(file_path: &str) -> Vec<Equation> {
-    let content = read_to_string(file_path).unwrap();
-    let lines = content.lines();
+fn parse_input(input: &str) -> Vec<Equation> {
+    let lines = input.lines();
     let mut input: Vec<Equation> = vec![];
 
     for l in lines {
         let mut split = l.split(": ");
         let total = split.next().unwrap().parse().unwrap();
-        let mut remainder = split.next().unwrap().split(" ");
+        let remainder = split.next().unwrap().split(" ");
         let mut values = vec![];
-        while let Some(s) = remainder.next() {
+        for s in remainder {
             values.push(s.parse().unwrap());
         }
 
-        let operators = Operators {
-            val: 0,
-            cap: (2 as u32).pow(values.len() as u32 - 1) - 1,
-        };
-
+        // Operators depend on the part, see `Equation::with_radix`
         input.push(Equation {
             total,
             values,
-            operators,
+            operators: Operators::default(),
         });
     }
 
     input
 }
 
-fn parse_input_v2(file_path: &str) -> Vec<Equation> {
-    let content = read_to_string(file_path).unwrap();
-    let lines = content.lines();
-    let mut input: Vec<Equation> = vec![];
+pub struct Day07;
 
-    for l in lines {
-        let mut split = l.split(": ");
-        let total = split.next().unwrap().parse().unwrap();
-        let mut remainder = split.next().unwrap().split(" ");
-        let mut values = vec![];
-        while let Some(s) = remainder.next() {
-            values.push(s.parse().unwrap());
-        }
+impl Solution for Day07 {
+    const DAY: u32 = 7;
+    const TITLE: &'static str = "Bridge Repair";
 
-        let operators = Operators {
-            val: 0,
-            cap: (3 as u32).pow(values.len() as u32 - 1) - 1,
-        };
+    type Input = Vec<Equation>;
+    type Answer = u64;
 
-        input.push(Equation {
-            total,
-            values,
-            operators,
-        });
+    fn parse(input: &str) -> Self::Input {
+        parse_input(input)
     }
 
-    input
-}
+    fn part1(input: &Self::Input) -> u64 {
+        day7(input)
+    }
 
-pub fn main(s: &str) -> Result<u64, FileNotFound> {
-    match s {
-        "example" => match get_test_file(EXAMPLE, "07") {
-            Err(err) => Err(err),
-            Ok(file) => Ok(day7(parse_input(&file))),
-        },
-        "actual" => match get_test_file(ACTUAL, "07") {
-            Err(err) => Err(err),
-            Ok(file) => Ok(day7(parse_input(&file))),
-        },
-        "example_v2" => match get_test_file(EXAMPLE, "07") {
-            Err(err) => Err(err),
-            Ok(file) => Ok(day7_v2(parse_input_v2(&file))),
-        },
-        "actual_v2" => match get_test_file(ACTUAL, "07") {
-            Err(err) => Err(err),
-            Ok(file) => Ok(day7_v2(parse_input_v2(&file))),
-        },
-        _ => todo!(),
+    fn part2(input: &Self::Input) -> u64 {
+        day7_v2(input)
     }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::solution::{solve, Part};
+    use crate::utils::EXAMPLE;
 
     #[test]
     fn test_example() {
-        assert_eq!(main("example").unwrap(), 3749);
+        assert_eq!(solve::<Day07>(EXAMPLE, Part::One).unwrap(), 3749);
     }
     #[test]
     fn test_example_v2() {
-        assert_eq!(main("example_v2").unwrap(), 11387);
+        assert_eq!(solve::<Day07>(EXAMPLE, Part::Two).unwrap(), 11387);
     }
 
     #[test]
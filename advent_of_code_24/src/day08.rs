@@ -1,15 +1,15 @@
 use std::{
     collections::{HashMap, HashSet},
-    fs::read_to_string,
     num::ParseIntError,
 };
 
 use combinatorial::Combinations;
+use tracing::{debug, trace};
 
-use crate::utils::{get_test_file, FileNotFound, ACTUAL, EXAMPLE};
+use crate::solution::{checked, Solution};
 
 #[derive(Debug)]
-struct Input {
+pub struct Input {
     size: (usize, usize),
     antenna_map: HashMap<char, Vec<(isize, isize)>>,
 }
@@ -32,37 +32,34 @@ struct Antinodes {
 impl Antinodes {
     fn new(input: &Input) -> Self {
         Self {
-            map_size: input.size.clone(),
+            map_size: input.size,
             antinode_locations: HashSet::new(),
         }
     }
 
-    fn is_within_map(self: &Self, pos: (isize, isize)) -> bool {
-        if pos.0 < 0 || pos.0 as usize >= self.map_size.0 {
-            return false;
-        } else if pos.1 < 0 || pos.1 as usize >= self.map_size.1 {
-            return false;
-        } else {
-            return true;
-        }
+    fn is_within_map(&self, pos: (isize, isize)) -> bool {
+        pos.0 >= 0
+            && (pos.0 as usize) < self.map_size.0
+            && pos.1 >= 0
+            && (pos.1 as usize) < self.map_size.1
     }
 
     fn add_location_if_within(&mut self, pos: (isize, isize)) -> bool {
         if self.is_within_map(pos) {
             self.antinode_locations
                 .insert((pos.0 as usize, pos.1 as usize));
-            return true;
+            true
         } else {
-            return false;
+            false
         }
     }
 
     fn count(self) -> u32 {
-        self.antinode_locations.len() as u32
+        checked(self.antinode_locations.len())
     }
 
     #[allow(dead_code)]
-    fn print_map(&self, input: &Input) -> () {
+    fn print_map(&self, input: &Input) {
         let mut map: Vec<Vec<char>> = vec![vec!['.'; self.map_size.1]; self.map_size.0];
         for (k, v) in input.antenna_map.iter() {
             for (i, j) in v {
@@ -80,24 +77,24 @@ impl Antinodes {
 }
 
 trait IsAntenna {
-    fn is_antenna(self) -> bool;
+    fn is_antenna(&self) -> bool;
 }
 
 impl IsAntenna for char {
     // Char is an antenna if lowercase, uppercase or digit
-    fn is_antenna(self) -> bool {
+    fn is_antenna(&self) -> bool {
         self.is_ascii_alphanumeric()
     }
 }
 
-fn day8(inputs: Input) -> Result<u32, ParseIntError> {
-    let mut antinodes = Antinodes::new(&inputs);
+fn day8(inputs: &Input) -> Result<u32, ParseIntError> {
+    let mut antinodes = Antinodes::new(inputs);
     inputs.antenna_map.values().for_each(|v| {
         let combs = Combinations::of_size(v, 2);
         // Antenna positions for each antenna type
         // Need a two-by-two combination to calculate the diametrical distances
         combs.for_each(|v| {
-            let v0 = v.get(0).unwrap();
+            let v0 = v.first().unwrap();
             let v1 = v.get(1).unwrap();
 
             let dist0 = v1.0 - v0.0;
@@ -110,19 +107,19 @@ fn day8(inputs: Input) -> Result<u32, ParseIntError> {
             antinodes.add_location_if_within(pos2);
         });
     });
-    // println!("{:?}", antinodes);
-    // println!("{:?}", inputs);
+    debug!("{:?}", antinodes);
+    trace!("{:?}", inputs);
     Ok(antinodes.count())
 }
 
-fn day8_v2(inputs: Input) -> Result<u32, ParseIntError> {
-    let mut antinodes = Antinodes::new(&inputs);
+fn day8_v2(inputs: &Input) -> Result<u32, ParseIntError> {
+    let mut antinodes = Antinodes::new(inputs);
     inputs.antenna_map.values().for_each(|v| {
         let combs = Combinations::of_size(v, 2);
         // Antenna positions for each antenna type
         // Need a two-by-two combination to calculate the diametrical distances
         combs.for_each(|v| {
-            let v0 = v.get(0).unwrap();
+            let v0 = v.first().unwrap();
             let v1 = v.get(1).unwrap();
 
             let dist0 = v1.0 - v0.0;
@@ -148,25 +145,21 @@ fn day8_v2(inputs: Input) -> Result<u32, ParseIntError> {
             }
         });
     });
-    // println!("{:?}", antinodes);
-    // println!("{:?}", inputs);
+    debug!("{:?}", antinodes);
+    trace!("{:?}", inputs);
 
     // antinodes.print_map(&inputs);
     Ok(antinodes.count())
 }
 
-fn parse_input(filepath: &str) -> Input {
+fn parse_input(input: &str) -> Input {
     let mut result = Input::new();
-    let input = read_to_string(filepath).unwrap();
     let lines: Vec<&str> = input.lines().collect();
     let n = lines.len();
     let m = lines[0].len();
     result.size = (n, m);
-    for i in 0..n {
-        let chars: Vec<char> = lines[i].chars().collect();
-
-        for j in 0..m {
-            let c = chars[j];
+    for (i, line) in lines.iter().enumerate() {
+        for (j, c) in line.chars().enumerate() {
             if c.is_antenna() {
                 result
                     .antenna_map
@@ -180,39 +173,41 @@ fn parse_input(filepath: &str) -> Input {
     result
 }
 
-pub fn main(s: &str) -> Result<u32, FileNotFound> {
-    match s {
-        "example" => match get_test_file(EXAMPLE, "08") {
-            Err(err) => Err(err),
-            Ok(file) => Ok(day8(parse_input(&file)).unwrap()),
-        },
-        "actual" => match get_test_file(ACTUAL, "08") {
-            Err(err) => Err(err),
-            Ok(file) => Ok(day8(parse_input(&file)).unwrap()),
-        },
-        "example_v2" => match get_test_file(EXAMPLE, "08") {
-            Err(err) => Err(err),
-            Ok(file) => Ok(day8_v2(parse_input(&file)).unwrap()),
-        },
-        "actual_v2" => match get_test_file(ACTUAL, "08") {
-            Err(err) => Err(err),
-            Ok(file) => Ok(day8_v2(parse_input(&file)).unwrap()),
-        },
-        _ => todo!(),
+pub struct Day08;
+
+impl Solution for Day08 {
+    const DAY: u32 = 8;
+    const TITLE: &'static str = "Resonant Collinearity";
+
+    type Input = Input;
+    type Answer = u32;
+
+    fn parse(input: &str) -> Self::Input {
+        parse_input(input)
+    }
+
+    fn part1(input: &Self::Input) -> u32 {
+        day8(input).unwrap()
+    }
+
+    fn part2(input: &Self::Input) -> u32 {
+        day8_v2(input).unwrap()
     }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::solution::{solve, Part};
+    use crate::utils::EXAMPLE;
 
     #[test]
     fn test_example() {
-        assert_eq!(main("example").unwrap(), 14);
+        assert_eq!(solve::<Day08>(EXAMPLE, Part::One).unwrap(), 14);
     }
 
     #[test]
     fn test_example_v2() {
-        assert_eq!(main("example_v2").unwrap(), 34);
+        assert_eq!(solve::<Day08>(EXAMPLE, Part::Two).unwrap(), 34);
     }
 }
@@ -1,19 +1,21 @@
 use std::fmt;
-use std::{fs::read_to_string, num::ParseIntError};
+use std::num::ParseIntError;
 
-use crate::utils::{get_test_file, FileNotFound, ACTUAL, EXAMPLE};
+use tracing::{debug, trace};
+
+use crate::solution::Solution;
 
 #[derive(Debug, PartialEq, Clone, Copy)]
 enum State {
-    FILE,
-    FREE,
+    File,
+    Free,
 }
 
 impl State {
     fn change_state(&mut self) -> Self {
         match self {
-            State::FILE => State::FREE,
-            State::FREE => State::FILE,
+            State::File => State::Free,
+            State::Free => State::File,
         }
     }
 }
@@ -25,26 +27,29 @@ struct Block {
     size: usize,
 }
 
-impl Block {
-    fn to_string(&self) -> String {
-        match self.state {
-            State::FREE => (0..self.size).map(|_| ".").collect(),
-            State::FILE => (0..self.size)
+impl fmt::Display for Block {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let s: String = match self.state {
+            State::Free => (0..self.size).map(|_| ".").collect(),
+            State::File => (0..self.size)
                 .map(|_| self.id.unwrap().to_string())
                 .collect(),
-        }
+        };
+        write!(f, "{s}")
     }
+}
 
+impl Block {
     fn is_free(&self) -> bool {
         match self.state {
-            State::FREE => true,
-            State::FILE => false,
+            State::Free => true,
+            State::File => false,
         }
     }
 }
 
 #[derive(Debug)]
-struct Input {
+pub struct Input {
     file_blocks: Vec<Block>,
 }
 
@@ -72,7 +77,7 @@ trait SliceExt {
 
 impl SliceExt for [Block] {
     fn trim_r(&self) -> &Self {
-        if let Some(last) = self.iter().rposition(|c| c.state != State::FREE) {
+        if let Some(last) = self.iter().rposition(|c| c.state != State::Free) {
             &self[0..last + 1]
         } else {
             unreachable!();
@@ -90,7 +95,7 @@ impl ToString for Vec<Block> {
     }
 }
 
-fn day9(input: Input) -> Result<u64, ParseIntError> {
+fn day9(input: &Input) -> Result<u64, ParseIntError> {
     // 0...11..2222
     // becomes
     //  Block{id:0, size:1},
@@ -126,25 +131,23 @@ fn day9(input: Input) -> Result<u64, ParseIntError> {
     let mut j: usize = blocks.len() - 1;
 
     for mut i in 0..blocks.len() {
-        // println!("i: {i}, j: {j}");
-        // println!("{:?}", blocks.to_string());
+        trace!("i: {i}, j: {j}");
+        trace!("{:?}", blocks.to_string());
 
         if blocks[i].is_free() {
             if blocks[i].size < blocks[j].size {
                 blocks[i].id = blocks[j].id;
-                blocks[i].state = State::FILE;
+                blocks[i].state = State::File;
                 blocks[j].size -= blocks[i].size;
             } else if blocks[i].size > blocks[j].size {
                 blocks[i].size -= blocks[j].size;
                 let block = blocks[j];
-                blocks[j].state = State::FREE;
+                blocks[j].state = State::Free;
                 blocks[j].id = None;
                 blocks.insert(i, block);
                 i += 1;
             } else {
-                let block = blocks[j];
-                blocks[j] = blocks[i];
-                blocks[i] = block;
+                blocks.swap(j, i);
             }
             while blocks[j].is_free() {
                 j -= 1;
@@ -156,7 +159,7 @@ fn day9(input: Input) -> Result<u64, ParseIntError> {
         }
     }
 
-    // println!("{:?}", blocks.to_string());
+    debug!("{:?}", blocks.to_string());
 
     let mut result: u64 = 0;
     let mut pos: usize = 0;
@@ -169,7 +172,7 @@ fn day9(input: Input) -> Result<u64, ParseIntError> {
     Ok(result)
 }
 
-fn day9_v2(input: Input) -> Result<u64, ParseIntError> {
+fn day9_v2(input: &Input) -> Result<u64, ParseIntError> {
     let mut blocks: Vec<Block> = input.file_blocks.trim_r().to_vec();
 
     let mut j = blocks.len() - 1;
@@ -183,15 +186,13 @@ fn day9_v2(input: Input) -> Result<u64, ParseIntError> {
                 if blocks[i].size > blocks[j].size {
                     blocks[i].size -= blocks[j].size;
                     let block = blocks[j];
-                    blocks[j].state = State::FREE;
+                    blocks[j].state = State::Free;
                     blocks[j].id = None;
                     blocks.insert(i, block);
                     j += 1;
                     break;
                 } else if blocks[i].size == blocks[j].size {
-                    let block = blocks[j];
-                    blocks[j] = blocks[i];
-                    blocks[i] = block;
+                    blocks.swap(j, i);
                     break;
                 }
             }
@@ -200,16 +201,16 @@ fn day9_v2(input: Input) -> Result<u64, ParseIntError> {
         j -= 1;
     }
 
-    // println!("{:?}", blocks.to_string());
+    debug!("{:?}", blocks.to_string());
 
     let mut result: u64 = 0;
     let mut pos: usize = 0;
     for b in blocks {
         match b.state {
-            State::FREE => {
+            State::Free => {
                 pos += b.size;
             }
-            State::FILE => {
+            State::File => {
                 for _ in 0..b.size {
                     result += (pos as u64) * (b.id.unwrap() as u64);
                     pos += 1;
@@ -220,73 +221,71 @@ fn day9_v2(input: Input) -> Result<u64, ParseIntError> {
     Ok(result)
 }
 
-fn parse_input(filepath: &str) -> Input {
-    let mut state: State = State::FILE;
+fn parse_input(input: &str) -> Input {
+    let mut state: State = State::File;
     let mut result: Input = Input::new();
     let mut file_id: usize = 0;
-    read_to_string(filepath)
-        .unwrap()
-        .trim()
-        .chars()
-        .for_each(|c| {
-            let d = c.to_digit(10).unwrap();
-            match state {
-                State::FILE => {
-                    result.push_block(Block {
-                        id: Some(file_id),
-                        size: d as usize,
-                        state: State::FILE,
-                    });
-                    file_id += 1;
-                }
-                State::FREE => result.push_block(Block {
-                    id: None,
+    input.trim().chars().for_each(|c| {
+        let d = c.to_digit(10).unwrap();
+        match state {
+            State::File => {
+                result.push_block(Block {
+                    id: Some(file_id),
                     size: d as usize,
-                    state: State::FREE,
-                }),
+                    state: State::File,
+                });
+                file_id += 1;
             }
-            state = state.change_state();
-        });
+            State::Free => result.push_block(Block {
+                id: None,
+                size: d as usize,
+                state: State::Free,
+            }),
+        }
+        state = state.change_state();
+    });
 
-    // println!("{:?}", result);
+    trace!("{:?}", result);
     result
 }
 
-pub fn main(s: &str) -> Result<u64, FileNotFound> {
-    match s {
-        "example" => match get_test_file(EXAMPLE, "09") {
-            Err(err) => Err(err),
-            Ok(file) => Ok(day9(parse_input(&file)).unwrap()),
-        },
-        "actual" => match get_test_file(ACTUAL, "09") {
-            Err(err) => Err(err),
-            Ok(file) => Ok(day9(parse_input(&file)).unwrap()),
-        },
-        "example_v2" => match get_test_file(EXAMPLE, "09") {
-            Err(err) => Err(err),
-            Ok(file) => Ok(day9_v2(parse_input(&file)).unwrap()),
-        },
-        "actual_v2" => match get_test_file(ACTUAL, "09") {
-            Err(err) => Err(err),
-            Ok(file) => Ok(day9_v2(parse_input(&file)).unwrap()),
-        },
-        _ => todo!(),
+pub struct Day09;
+
+impl Solution for Day09 {
+    const DAY: u32 = 9;
+    const TITLE: &'static str = "Disk Fragmenter";
+
+    type Input = Input;
+    type Answer = u64;
+
+    fn parse(input: &str) -> Self::Input {
+        parse_input(input)
+    }
+
+    fn part1(input: &Self::Input) -> u64 {
+        day9(input).unwrap()
+    }
+
+    fn part2(input: &Self::Input) -> u64 {
+        day9_v2(input).unwrap()
     }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::solution::{solve, Part};
+    use crate::utils::{read_test_file, EXAMPLE};
 
     #[test]
     fn test_example() {
-        assert_eq!(main("example").unwrap(), 1928);
+        assert_eq!(solve::<Day09>(EXAMPLE, Part::One).unwrap(), 1928);
     }
 
     #[test]
     fn test_input() {
         assert_eq!(
-            parse_input(&get_test_file(EXAMPLE, "09").unwrap())
+            parse_input(&read_test_file(EXAMPLE, "09").unwrap())
                 .file_blocks
                 .to_string(),
             "00...111...2...333.44.5555.6666.777.888899".to_string()
@@ -295,6 +294,6 @@ mod tests {
 
     #[test]
     fn test_example_v2() {
-        assert_eq!(main("example_v2").unwrap(), 2858);
+        assert_eq!(solve::<Day09>(EXAMPLE, Part::Two).unwrap(), 2858);
     }
 }
@@ -1,7 +1,11 @@
-use crate::utils::{get_test_file, Board, Direction, FileNotFound, ACTUAL, EXAMPLE};
-use std::{fmt, fs::read_to_string};
+use tracing::debug;
 
-struct TrailMap {
+use crate::solution::Solution;
+use crate::utils::{Board, Direction};
+use std::fmt;
+
+#[derive(Clone)]
+pub struct TrailMap {
     trail_map: Board<u8>,
     visited: Board<bool>,
     trailhead_count: u32,
@@ -79,7 +83,7 @@ impl TrailMap {
 }
 
 impl fmt::Display for TrailMap {
-    fn fmt(self: &Self, f: &mut fmt::Formatter) -> fmt::Result {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         writeln!(
             f,
             concat!("Count: {}\n\n", "Map:\n\t{}\n\n", "Visited:\n\t{}"),
@@ -88,8 +92,9 @@ impl fmt::Display for TrailMap {
     }
 }
 
-fn day10(mut trail: TrailMap) -> u32 {
-    // println!("Starting trail: \n{}\n", trail);
+fn day10(trail: &TrailMap) -> u32 {
+    let mut trail = trail.clone();
+    debug!("Starting trail: \n{}\n", trail);
     for i in 0..trail.trail_map.len() {
         for j in 0..trail.trail_map[i].len() {
             if trail.trail_map[i][j] == 0 {
@@ -99,12 +104,13 @@ fn day10(mut trail: TrailMap) -> u32 {
             }
         }
     }
-    // println!("Final trail: \n{}\n", trail);
+    debug!("Final trail: \n{}\n", trail);
     trail.trailhead_count
 }
 
-fn day10_v2(mut trail: TrailMap) -> u32 {
-    // println!("Starting trail: \n{}\n", trail);
+fn day10_v2(trail: &TrailMap) -> u32 {
+    let mut trail = trail.clone();
+    debug!("Starting trail: \n{}\n", trail);
     for i in 0..trail.trail_map.len() {
         for j in 0..trail.trail_map[i].len() {
             if trail.trail_map[i][j] == 0 {
@@ -113,53 +119,55 @@ fn day10_v2(mut trail: TrailMap) -> u32 {
             }
         }
     }
-    // println!("Final trail: \n{}\n", trail);
+    debug!("Final trail: \n{}\n", trail);
     trail.trailhead_count
 }
 
-fn parse_input(filepath: &str) -> TrailMap {
+fn parse_input(input: &str) -> TrailMap {
     let mut trail_map: Vec<Vec<u8>> = vec![];
 
-    read_to_string(filepath).unwrap().lines().for_each(|l| {
+    input.lines().for_each(|l| {
         trail_map.push(l.chars().map(|c| c.to_digit(10).unwrap() as u8).collect());
     });
 
     TrailMap::new(trail_map)
 }
 
-pub fn main(s: &str) -> Result<u32, FileNotFound> {
-    match s {
-        "example" => match get_test_file(EXAMPLE, "10") {
-            Err(err) => Err(err),
-            Ok(file) => Ok(day10(parse_input(&file))),
-        },
-        "actual" => match get_test_file(ACTUAL, "10") {
-            Err(err) => Err(err),
-            Ok(file) => Ok(day10(parse_input(&file))),
-        },
-        "example_v2" => match get_test_file(EXAMPLE, "10") {
-            Err(err) => Err(err),
-            Ok(file) => Ok(day10_v2(parse_input(&file))),
-        },
-        "actual_v2" => match get_test_file(ACTUAL, "10") {
-            Err(err) => Err(err),
-            Ok(file) => Ok(day10_v2(parse_input(&file))),
-        },
-        _ => todo!(),
+pub struct Day10;
+
+impl Solution for Day10 {
+    const DAY: u32 = 10;
+    const TITLE: &'static str = "Hoof It";
+
+    type Input = TrailMap;
+    type Answer = u32;
+
+    fn parse(input: &str) -> Self::Input {
+        parse_input(input)
+    }
+
+    fn part1(input: &Self::Input) -> u32 {
+        day10(input)
+    }
+
+    fn part2(input: &Self::Input) -> u32 {
+        day10_v2(input)
     }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::solution::{solve, Part};
+    use crate::utils::EXAMPLE;
 
     #[test]
     fn test_example() {
-        assert_eq!(main("example").unwrap(), 36);
+        assert_eq!(solve::<Day10>(EXAMPLE, Part::One).unwrap(), 36);
     }
 
     #[test]
     fn test_example_v2() {
-        assert_eq!(main("example_v2").unwrap(), 81);
+        assert_eq!(solve::<Day10>(EXAMPLE, Part::Two).unwrap(), 81);
     }
 }
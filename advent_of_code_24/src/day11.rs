@@ -1,11 +1,11 @@
-use std::{
-    collections::{HashMap, VecDeque},
-    fs::read_to_string,
-};
+use std::collections::{HashMap, VecDeque};
+use std::fmt;
 
-use crate::utils::{get_test_file, FileNotFound, ACTUAL, EXAMPLE};
+use tracing::{debug, trace};
 
-#[derive(Debug, Hash, PartialEq, Eq)]
+use crate::solution::{checked, Part, Solution, Variant};
+
+#[derive(Clone, Debug, Hash, PartialEq, Eq)]
 struct Stone(u64);
 
 impl Stone {
@@ -18,7 +18,7 @@ impl Stone {
     }
 
     fn is_engraving_even_digits(&self) -> bool {
-        self.0.to_string().len() % 2 == 0
+        self.0.to_string().len().is_multiple_of(2)
     }
 
     fn split_stone(&self) -> (Self, Self) {
@@ -32,7 +32,8 @@ impl Stone {
     }
 }
 
-struct StoneVec(VecDeque<Stone>);
+#[derive(Clone)]
+pub struct StoneVec(VecDeque<Stone>);
 
 impl StoneVec {
     fn new(stones: VecDeque<Stone>) -> Self {
@@ -65,22 +66,33 @@ impl StoneVec {
 }
 
 #[derive(Debug)]
-struct StoneMap(HashMap<Stone, usize>);
+struct StoneMap(HashMap<Stone, u64>);
+
+impl fmt::Display for StoneMap {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let counts: Vec<String> = self
+            .0
+            .iter()
+            .map(|(stone, count)| format!("{}: {count}", stone.0))
+            .collect();
+        write!(f, "{}, {}", self.count(), counts.join(", "))
+    }
+}
 
 impl StoneMap {
-    fn new(stones: HashMap<Stone, usize>) -> Self {
+    fn new(stones: HashMap<Stone, u64>) -> Self {
         StoneMap(stones)
     }
 
-    fn from_vec(stone_vec: StoneVec) -> Self {
+    fn from_vec(stone_vec: &StoneVec) -> Self {
         let mut stone_map = HashMap::new();
-        for stone in stone_vec.0 {
-            stone_map.insert(stone, 1);
+        for stone in stone_vec.0.iter() {
+            stone_map.insert(stone.clone(), 1);
         }
         StoneMap(stone_map)
     }
 
-    fn insert_or_add_count(&mut self, stone: Stone, count: usize) -> &mut usize {
+    fn insert_or_add_count(&mut self, stone: Stone, count: u64) -> &mut u64 {
         self.0
             .entry(stone)
             .and_modify(|n| *n += count)
@@ -98,50 +110,38 @@ impl StoneMap {
                 next_stones.insert_or_add_count(stone.change(), count);
             }
         }
-        // println!(
-        //     "{}, {}",
-        //     next_stones.count(),
-        //     next_stones
-        //         .0
-        //         .iter()
-        //         .map(|(stone, count)| format!("{}: {count}", stone.0 as u64))
-        //         .collect::<Vec<String>>()
-        //         .join(", ")
-        // );
-        // println!();
+        trace!("{next_stones}");
         next_stones
     }
 
-    fn count(&self) -> u32 {
-        self.0.values().sum::<usize>() as u32
+    fn count(&self) -> u64 {
+        self.0.values().sum()
     }
 }
 
-fn day11(mut stones: StoneVec) -> u32 {
+fn day11(stones: &StoneVec) -> u64 {
+    let mut stones = stones.clone();
     let blinks = 25;
     for _b in 0..blinks {
         stones = stones.evolve_stones();
     }
 
-    // println!("{:?}", stones.0);
-    stones.len() as u32
+    trace!("{:?}", stones.0);
+    checked(stones.len())
 }
 
-fn day11_v2(stones: StoneVec) -> u32 {
+fn day11_v2(stones: &StoneVec) -> u64 {
+    evolve_map(stones, 75)
+}
+
+// Same as `day11`, counting stones with a map instead of keeping every one
+fn day11_map(stones: &StoneVec) -> u64 {
+    evolve_map(stones, 25)
+}
+
+fn evolve_map(stones: &StoneVec, blinks: usize) -> u64 {
     let mut stone_map = StoneMap::from_vec(stones);
-    // println!("{:?}", stone_map);
-    // println!(
-    //     "{}, {}",
-    //     stone_map.count(),
-    //     stone_map
-    //         .0
-    //         .iter()
-    //         .map(|(stone, count)| format!("{}: {count}", stone.0 as u64))
-    //         .collect::<Vec<String>>()
-    //         .join(", ")
-    // );
-    // println!();
-    let blinks = 75;
+    debug!("{stone_map}");
     for _b in 0..blinks {
         stone_map = stone_map.evolve_stones();
     }
@@ -149,10 +149,9 @@ fn day11_v2(stones: StoneVec) -> u32 {
     stone_map.count()
 }
 
-fn parse_input(filepath: &str) -> StoneVec {
+fn parse_input(input: &str) -> StoneVec {
     let stones = VecDeque::from_iter(
-        read_to_string(filepath)
-            .unwrap()
+        input
             .trim()
             .split(" ")
             .map(|s| Stone(s.parse::<u64>().unwrap())),
@@ -161,39 +160,52 @@ fn parse_input(filepath: &str) -> StoneVec {
     StoneVec::new(stones)
 }
 
-pub fn main(s: &str) -> Result<u32, FileNotFound> {
-    match s {
-        "example" => match get_test_file(EXAMPLE, "11") {
-            Err(err) => Err(err),
-            Ok(file) => Ok(day11(parse_input(&file))),
-        },
-        "actual" => match get_test_file(ACTUAL, "11") {
-            Err(err) => Err(err),
-            Ok(file) => Ok(day11(parse_input(&file))),
-        },
-        "example_v2" => match get_test_file(EXAMPLE, "11") {
-            Err(err) => Err(err),
-            Ok(file) => Ok(day11_v2(parse_input(&file))),
-        },
-        "actual_v2" => match get_test_file(ACTUAL, "11") {
-            Err(err) => Err(err),
-            Ok(file) => Ok(day11_v2(parse_input(&file))),
-        },
-        _ => todo!(),
+pub struct Day11;
+
+impl Solution for Day11 {
+    const DAY: u32 = 11;
+    const TITLE: &'static str = "Plutonian Pebbles";
+    const VARIANTS: &'static [Variant<Self>] = &[Variant {
+        name: "map",
+        part: Part::One,
+        solve: day11_map,
+    }];
+
+    type Input = StoneVec;
+    type Answer = u64;
+
+    fn parse(input: &str) -> Self::Input {
+        parse_input(input)
+    }
+
+    fn part1(input: &Self::Input) -> u64 {
+        day11(input)
+    }
+
+    fn part2(input: &Self::Input) -> u64 {
+        day11_v2(input)
     }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::solution::solve;
+    use crate::utils::{read_test_file, EXAMPLE};
 
     #[test]
     fn test_example() {
-        assert_eq!(main("example").unwrap(), 55312);
+        assert_eq!(solve::<Day11>(EXAMPLE, Part::One).unwrap(), 55312);
     }
 
     #[test]
     fn test_example_v2() {
-        assert_eq!(main("example_v2").unwrap(), 4003138674);
+        assert_eq!(solve::<Day11>(EXAMPLE, Part::Two).unwrap(), 65601038650482);
+    }
+
+    #[test]
+    fn test_example_map() {
+        let stones = Day11::parse(&read_test_file(EXAMPLE, "11").unwrap());
+        assert_eq!(day11_map(&stones), 55312);
     }
 }
@@ -1,8 +1,10 @@
-use std::fs::read_to_string;
+use tracing::{debug, trace};
 
-use crate::utils::{get_test_file, Board, Direction, FileNotFound, ACTUAL, EXAMPLE};
+use crate::solution::Solution;
+use crate::utils::{Board, Direction};
 
-struct Garden {
+#[derive(Clone)]
+pub struct Garden {
     board: Board<char>,
     visited: Board<bool>,
     perimeter: u32,
@@ -32,15 +34,15 @@ impl Garden {
 
         for dir in Direction::ORTHOGONALS {
             let next_pos = self.board.add_direction(&dir, pos);
-            // println!("Curr pos: {:?}, Next pos: {:?}", pos, next_pos);
+            trace!("Curr pos: {:?}, Next pos: {:?}", pos, next_pos);
             match next_pos {
                 None => self.perimeter += 1,
                 Some(next_pos) => {
                     if self.board[next_pos.0][next_pos.1] != self.board[pos.0][pos.1] {
-                        // println!("Next position is not part of region");
+                        trace!("Next position is not part of region");
                         self.perimeter += 1;
                     } else if !self.visited[next_pos.0][next_pos.1] {
-                        // println!("Next position is part of region");
+                        trace!("Next position is part of region");
                         self.find_region(next_pos);
                     }
                 }
@@ -49,19 +51,20 @@ impl Garden {
     }
 }
 
-fn day12(mut garden: Garden) -> u32 {
-    // println!("{}", garden.board);
-    // println!("{}", garden.visited);
+fn day12(garden: &Garden) -> u32 {
+    let mut garden = garden.clone();
+    debug!("{}", garden.board);
+    trace!("{}", garden.visited);
 
     let mut result: u32 = 0;
     for i in 0..garden.board.len() {
         for j in 0..garden.board[i].len() {
             if !garden.visited[i][j] {
                 garden.find_region((i, j));
-                // println!(
-                //     "{}: Area {} * Perimeter {}",
-                //     garden.board[i][j], garden.area, garden.perimeter
-                // );
+                debug!(
+                    "{}: Area {} * Perimeter {}",
+                    garden.board[i][j], garden.area, garden.perimeter
+                );
                 result += garden.area * garden.perimeter;
                 garden.area = 0;
                 garden.perimeter = 0;
@@ -69,58 +72,60 @@ fn day12(mut garden: Garden) -> u32 {
         }
     }
 
-    // println!("{}", garden.visited);
+    trace!("{}", garden.visited);
     result
 }
 
-fn day12_v2(_garden: Garden) -> u32 {
-    // println!("{}", garden.board);
-    // println!("{}", garden.visited);
+fn day12_v2(garden: &Garden) -> u32 {
+    debug!("{}", garden.board);
+    trace!("{}", garden.visited);
     0
 }
 
-fn parse_input(filepath: &str) -> Garden {
+fn parse_input(input: &str) -> Garden {
     let mut board: Vec<Vec<char>> = vec![];
-    read_to_string(filepath).unwrap().lines().for_each(|l| {
+    input.lines().for_each(|l| {
         board.push(l.chars().collect());
     });
 
     Garden::new(board)
 }
 
-pub fn main(s: &str) -> Result<u32, FileNotFound> {
-    match s {
-        "example" => match get_test_file(EXAMPLE, "12") {
-            Err(err) => Err(err),
-            Ok(file) => Ok(day12(parse_input(&file))),
-        },
-        "actual" => match get_test_file(ACTUAL, "12") {
-            Err(err) => Err(err),
-            Ok(file) => Ok(day12(parse_input(&file))),
-        },
-        "example_v2" => match get_test_file(EXAMPLE, "12") {
-            Err(err) => Err(err),
-            Ok(file) => Ok(day12_v2(parse_input(&file))),
-        },
-        "actual_v2" => match get_test_file(ACTUAL, "12") {
-            Err(err) => Err(err),
-            Ok(file) => Ok(day12_v2(parse_input(&file))),
-        },
-        _ => todo!(),
+pub struct Day12;
+
+impl Solution for Day12 {
+    const DAY: u32 = 12;
+    const TITLE: &'static str = "Garden Groups";
+
+    type Input = Garden;
+    type Answer = u32;
+
+    fn parse(input: &str) -> Self::Input {
+        parse_input(input)
+    }
+
+    fn part1(input: &Self::Input) -> u32 {
+        day12(input)
+    }
+
+    fn part2(input: &Self::Input) -> u32 {
+        day12_v2(input)
     }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::solution::{solve, Part};
+    use crate::utils::EXAMPLE;
 
     #[test]
     fn test_example() {
-        assert_eq!(main("example").unwrap(), 1930);
+        assert_eq!(solve::<Day12>(EXAMPLE, Part::One).unwrap(), 1930);
     }
 
     // #[test]
     // fn test_example_v2() {
-    //     assert_eq!(main("example_v2").unwrap(), 10);
+    //     assert_eq!(solve::<Day12>(EXAMPLE, Part::Two).unwrap(), 10);
     // }
 }
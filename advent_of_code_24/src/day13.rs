@@ -1,6 +1,6 @@
-use std::{fs::read_to_string, str::Split};
+use std::str::Split;
 
-use crate::utils::{get_test_file, FileNotFound, ACTUAL, EXAMPLE};
+use crate::solution::Solution;
 
 enum State {
     A,
@@ -18,7 +18,7 @@ impl State {
     }
 }
 
-struct Input {
+pub struct Input {
     input: Vec<LinearSystem>,
 }
 
@@ -86,32 +86,32 @@ impl Equation {
         let equation = values
             .map(|s| s.split(sep).last().unwrap().parse::<i64>().unwrap())
             .collect::<Vec<i64>>();
-        let x = equation.get(0).expect("Index is out of bounds");
+        let x = equation.first().expect("Index is out of bounds");
         let y = equation.get(1).expect("Index is out of bounds");
 
         Equation { x: *x, y: *y }
     }
 
     fn from_slice(equation: [i64; 2]) -> Self {
-        let x = equation.get(0).expect("Index is out of bounds");
+        let x = equation.first().expect("Index is out of bounds");
         let y = equation.get(1).expect("Index is out of bounds");
 
         Equation { x: *x, y: *y }
     }
 }
 
-fn day13(input: Input) -> i64 {
+fn day13(input: &Input) -> i64 {
     let mut result: i64 = 0;
-    for system in input.input {
+    for system in &input.input {
         let (x, y) = system.solve_or_default(0);
         result += 3 * x + y;
     }
     result
 }
 
-fn day13_v2(input: Input) -> i64 {
+fn day13_v2(input: &Input) -> i64 {
     let mut result: i64 = 0;
-    for mut system in input.input {
+    for &(mut system) in &input.input {
         system.prize = Equation::from_slice([
             system.prize.x + 10000000000000,
             system.prize.y + 10000000000000,
@@ -122,12 +122,12 @@ fn day13_v2(input: Input) -> i64 {
     result
 }
 
-fn parse_input(filepath: &str) -> Input {
+fn parse_input(input: &str) -> Input {
     let mut result: Vec<LinearSystem> = vec![];
     let mut state = State::A;
     let mut linear_system = LinearSystem::new();
-    for l in read_to_string(filepath).unwrap().lines() {
-        if !l.eq("") {
+    for l in input.lines() {
+        if !l.is_empty() {
             let values = l.split(": ").last().unwrap().split(", ");
             linear_system.assign_equation(&state, values);
             state.next();
@@ -139,39 +139,41 @@ fn parse_input(filepath: &str) -> Input {
     Input { input: result }
 }
 
-pub fn main(s: &str) -> Result<i64, FileNotFound> {
-    match s {
-        "example" => match get_test_file(EXAMPLE, "13") {
-            Err(err) => Err(err),
-            Ok(file) => Ok(day13(parse_input(&file))),
-        },
-        "actual" => match get_test_file(ACTUAL, "13") {
-            Err(err) => Err(err),
-            Ok(file) => Ok(day13(parse_input(&file))),
-        },
-        "example_v2" => match get_test_file(EXAMPLE, "13") {
-            Err(err) => Err(err),
-            Ok(file) => Ok(day13_v2(parse_input(&file))),
-        },
-        "actual_v2" => match get_test_file(ACTUAL, "13") {
-            Err(err) => Err(err),
-            Ok(file) => Ok(day13_v2(parse_input(&file))),
-        },
-        _ => todo!(),
+pub struct Day13;
+
+impl Solution for Day13 {
+    const DAY: u32 = 13;
+    const TITLE: &'static str = "Claw Contraption";
+
+    type Input = Input;
+    type Answer = i64;
+
+    fn parse(input: &str) -> Self::Input {
+        parse_input(input)
+    }
+
+    fn part1(input: &Self::Input) -> i64 {
+        day13(input)
+    }
+
+    fn part2(input: &Self::Input) -> i64 {
+        day13_v2(input)
     }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::solution::{solve, Part};
+    use crate::utils::EXAMPLE;
 
     #[test]
     fn test_example() {
-        assert_eq!(main("example").unwrap(), 480);
+        assert_eq!(solve::<Day13>(EXAMPLE, Part::One).unwrap(), 480);
     }
 
     #[test]
     fn test_example_v2() {
-        assert_eq!(main("example_v2").unwrap(), 875318608908);
+        assert_eq!(solve::<Day13>(EXAMPLE, Part::Two).unwrap(), 875318608908);
     }
 }
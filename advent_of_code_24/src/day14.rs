@@ -1,6 +1,8 @@
-use std::{fmt, fs::read_to_string};
+use std::fmt;
 
-use crate::utils::{get_test_file, FileNotFound, ACTUAL, EXAMPLE};
+use tracing::debug;
+
+use crate::solution::{checked, Solution};
 
 enum Quadrant {
     UL,
@@ -29,7 +31,7 @@ impl Quadrant {
 }
 
 #[derive(Clone)]
-struct RobotBoard {
+pub struct RobotBoard {
     robots: Vec<Robot>,
     board_size: (usize, usize),
 }
@@ -50,16 +52,16 @@ impl RobotBoard {
             }
         }
 
-        quadrant_count.iter().fold(1, |acc, e| acc * e) as u32
+        checked(quadrant_count.iter().product::<usize>())
     }
 }
 impl fmt::Display for RobotBoard {
-    fn fmt(self: &Self, f: &mut fmt::Formatter) -> fmt::Result {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         let mut board = vec![vec!['.'; self.board_size.0]; self.board_size.1];
 
         for r in self.robots.iter() {
-            let x = r.pos.0 as usize;
-            let y = r.pos.1 as usize;
+            let x = r.pos.0;
+            let y = r.pos.1;
             match board[y][x] {
                 '.' => board[y][x] = '1',
                 _ => {
@@ -102,13 +104,15 @@ impl Robot {
     }
 }
 
-fn day14(mut robot_board: RobotBoard) -> u32 {
+fn day14(robot_board: &RobotBoard) -> u32 {
+    let mut robot_board = robot_board.clone();
     robot_board.move_robots(100);
-    // println!("{}", robot_board);
+    debug!("{}", robot_board);
     robot_board.compute_danger_level()
 }
 
-fn day14_v2(mut robot_board: RobotBoard) -> u32 {
+fn day14_v2(robot_board: &RobotBoard) -> u32 {
+    let mut robot_board = robot_board.clone();
     let mut danger_levels: Vec<u32> = vec![];
     let mut all_states: Vec<RobotBoard> = vec![];
     // 0 steps
@@ -126,11 +130,15 @@ fn day14_v2(mut robot_board: RobotBoard) -> u32 {
     let min_danger = danger_levels.iter().min().unwrap();
     let min_danger_index = danger_levels.iter().position(|x| x == min_danger).unwrap();
 
-    // println!("{}:\n{}", min_danger_index, all_states[min_danger_index]);
-    min_danger_index as u32
+    debug!("{}:\n{}", min_danger_index, all_states[min_danger_index]);
+    checked(min_danger_index)
 }
 
-fn parse_input(filepath: &str, board_size: (usize, usize)) -> RobotBoard {
+// The actual input is played on a bigger board than the example
+const BOARD_SIZE: (usize, usize) = (101, 103);
+const EXAMPLE_BOARD_SIZE: (usize, usize) = (11, 7);
+
+fn parse_input(input: &str, board_size: (usize, usize)) -> RobotBoard {
     fn strip_robot_info(values: &str, prefix: &str) -> (isize, isize) {
         let info = values
             .strip_prefix(prefix)
@@ -139,13 +147,13 @@ fn parse_input(filepath: &str, board_size: (usize, usize)) -> RobotBoard {
             .map(|s| s.parse::<isize>().unwrap())
             .collect::<Vec<isize>>();
         (
-            *info.get(0).expect("Unexpected parsing"),
+            *info.first().expect("Unexpected parsing"),
             *info.get(1).expect("Unexpected parsing"),
         )
     }
 
     let mut robots: Vec<Robot> = vec![];
-    read_to_string(filepath).unwrap().lines().for_each(|l| {
+    input.lines().for_each(|l| {
         // values = [ "p=0,4", "v=3,-3" ]
         let mut values = l.split(" ");
         let pos = strip_robot_info(values.next().unwrap(), "p=");
@@ -154,42 +162,71 @@ fn parse_input(filepath: &str, board_size: (usize, usize)) -> RobotBoard {
         robots.push(Robot::new((pos.0 as usize, pos.1 as usize), (vel.0, vel.1)));
     });
 
+    let fits = |size: (usize, usize)| robots.iter().all(|r| r.pos.0 < size.0 && r.pos.1 < size.1);
+    assert!(
+        fits(board_size),
+        "Robots are outside the {}x{} board, is this the example?",
+        board_size.0,
+        board_size.1
+    );
+    // An input this small can't tell the boards apart, so it is never guessed
+    assert!(
+        board_size == EXAMPLE_BOARD_SIZE || !fits(EXAMPLE_BOARD_SIZE),
+        "Robots all fit on the example's 11x7 board, solve it as the example"
+    );
+
     RobotBoard { robots, board_size }
 }
 
-pub fn main(s: &str) -> Result<u32, FileNotFound> {
-    match s {
-        "example" => match get_test_file(EXAMPLE, "14") {
-            Err(err) => Err(err),
-            Ok(file) => Ok(day14(parse_input(&file, (11, 7)))),
-        },
-        "actual" => match get_test_file(ACTUAL, "14") {
-            Err(err) => Err(err),
-            Ok(file) => Ok(day14(parse_input(&file, (101, 103)))),
-        },
-        "example_v2" => match get_test_file(EXAMPLE, "14") {
-            Err(err) => Err(err),
-            Ok(file) => Ok(day14_v2(parse_input(&file, (11, 7)))),
-        },
-        "actual_v2" => match get_test_file(ACTUAL, "14") {
-            Err(err) => Err(err),
-            Ok(file) => Ok(day14_v2(parse_input(&file, (101, 103)))),
-        },
-        _ => todo!(),
+pub struct Day14;
+
+impl Solution for Day14 {
+    const DAY: u32 = 14;
+    const TITLE: &'static str = "Restroom Redoubt";
+
+    type Input = RobotBoard;
+    type Answer = u32;
+
+    fn parse(input: &str) -> Self::Input {
+        parse_input(input, BOARD_SIZE)
+    }
+
+    fn parse_example(input: &str) -> Self::Input {
+        parse_input(input, EXAMPLE_BOARD_SIZE)
+    }
+
+    fn part1(input: &Self::Input) -> u32 {
+        day14(input)
+    }
+
+    fn part2(input: &Self::Input) -> u32 {
+        day14_v2(input)
     }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::solution::{solve, Part};
+    use crate::utils::EXAMPLE;
 
     #[test]
     fn test_example() {
-        assert_eq!(main("example").unwrap(), 12);
+        assert_eq!(solve::<Day14>(EXAMPLE, Part::One).unwrap(), 12);
     }
 
     #[test]
     fn test_example_v2() {
-        assert_eq!(main("example_v2").unwrap(), 0);
+        assert_eq!(solve::<Day14>(EXAMPLE, Part::Two).unwrap(), 0);
+    }
+
+    #[test]
+    fn test_board_size() {
+        let example = "p=0,4 v=3,-3\n";
+        let actual = "p=0,4 v=3,-3\np=50,90 v=1,1\n";
+        assert_eq!(Day14::parse_example(example).board_size, (11, 7));
+        assert_eq!(Day14::parse(actual).board_size, (101, 103));
+        assert!(std::panic::catch_unwind(|| Day14::parse(example)).is_err());
+        assert!(std::panic::catch_unwind(|| Day14::parse_example(actual)).is_err());
     }
 }
@@ -1,8 +1,9 @@
-use std::{fmt, fs::read_to_string};
+use tracing::{debug, trace};
 
-use crate::utils::{get_test_file, Board, Direction, FileNotFound, ACTUAL, EXAMPLE};
+use crate::solution::{checked, Solution};
+use crate::utils::{Board, Direction, Tile};
 
-use crate::day15_v2::main as main_v2;
+use crate::day15_v2;
 
 #[derive(PartialEq)]
 enum State {
@@ -22,7 +23,9 @@ impl State {
             _ => panic!("Unexpected symbol in input"),
         }
     }
+}
 
+impl Tile for State {
     fn to_char(&self) -> char {
         match self {
             State::Wall => '#',
@@ -33,20 +36,6 @@ impl State {
     }
 }
 
-impl fmt::Display for Board<State> {
-    fn fmt(self: &Self, f: &mut fmt::Formatter) -> fmt::Result {
-        writeln!(
-            f,
-            concat!("Board:\n\t{}"),
-            self.board
-                .iter()
-                .map(|v| v.iter().map(|s| s.to_char()).collect())
-                .collect::<Vec<String>>()
-                .join("\n\t"),
-        )
-    }
-}
-
 struct Input {
     board: Board<State>,
     robot_pos: (usize, usize),
@@ -79,7 +68,7 @@ impl Input {
             State::Robot => panic!("There should only be one robot"),
         }
 
-        // println!("{}", self.board);
+        trace!("{}", self.board);
     }
 
     fn push_box(&mut self, d: &Direction, pos: (usize, usize)) -> bool {
@@ -105,7 +94,7 @@ impl Input {
         }
     }
 
-    fn sum_box_gps(self) -> u32 {
+    fn sum_box_gps(self) -> u64 {
         let mut result = 0;
         for i in 0..self.board.len() {
             for j in 0..self.board[i].len() {
@@ -115,19 +104,26 @@ impl Input {
             }
         }
 
-        result as u32
+        checked(result)
     }
 }
 
-fn day15(mut input: Input) -> u32 {
+fn day15(mut input: Input) -> u64 {
     for d in input.moves.clone() {
         input.move_robot(&d);
     }
     input.sum_box_gps()
 }
 
-fn parse_input(filepath: &str) -> Input {
-    let mut board: Vec<Vec<State>> = vec![];
+// Raw warehouse map and robot moves, shared between both parts since part 2
+// widens the map before building its board
+pub struct Warehouse {
+    pub(crate) map: Vec<Vec<char>>,
+    pub(crate) moves: Vec<Direction>,
+}
+
+fn parse_input(input: &str) -> Warehouse {
+    let mut map: Vec<Vec<char>> = vec![];
     let mut moves: Vec<Direction> = vec![];
 
     enum ReadingMode {
@@ -135,17 +131,13 @@ fn parse_input(filepath: &str) -> Input {
         Moves,
     }
     let mut reading_mode = ReadingMode::Board;
-    read_to_string(filepath).unwrap().lines().for_each(|l| {
-        if l == "" {
+    input.lines().for_each(|l| {
+        if l.is_empty() {
             reading_mode = ReadingMode::Moves;
         } else {
             match reading_mode {
                 ReadingMode::Board => {
-                    board.push(
-                        l.chars()
-                            .map(|c| State::from_char(c))
-                            .collect::<Vec<State>>(),
-                    );
+                    map.push(l.chars().collect());
                 }
                 ReadingMode::Moves => {
                     l.chars().for_each(|c| moves.push(Direction::from_char(c)));
@@ -154,11 +146,21 @@ fn parse_input(filepath: &str) -> Input {
         }
     });
 
+    Warehouse { map, moves }
+}
+
+fn build_input(warehouse: &Warehouse) -> Input {
+    let board: Vec<Vec<State>> = warehouse
+        .map
+        .iter()
+        .map(|l| l.iter().map(|c| State::from_char(*c)).collect())
+        .collect();
+
     // TODO: Could be refactored
     let mut robot_pos: (usize, usize) = (0, 0);
-    for i in 0..board.len() {
-        for j in 0..board[i].len() {
-            if board[i][j] == State::Robot {
+    for (i, row) in board.iter().enumerate() {
+        for (j, state) in row.iter().enumerate() {
+            if *state == State::Robot {
                 robot_pos = (i, j);
             }
         }
@@ -166,34 +168,42 @@ fn parse_input(filepath: &str) -> Input {
     let input = Input {
         board: Board::new(board),
         robot_pos,
-        moves,
+        moves: warehouse.moves.clone(),
     };
-    // println!("{}", input.board);
+    debug!("{}", input.board);
     input
 }
 
-pub fn main(s: &str) -> Result<u32, FileNotFound> {
-    match s {
-        "example" => match get_test_file(EXAMPLE, "15") {
-            Err(err) => Err(err),
-            Ok(file) => Ok(day15(parse_input(&file))),
-        },
-        "actual" => match get_test_file(ACTUAL, "15") {
-            Err(err) => Err(err),
-            Ok(file) => Ok(day15(parse_input(&file))),
-        },
-        "example_v2" => main_v2("example_v2"),
-        "actual_v2" => main_v2("actual_v2"),
-        _ => todo!(),
+pub struct Day15;
+
+impl Solution for Day15 {
+    const DAY: u32 = 15;
+    const TITLE: &'static str = "Warehouse Woes";
+
+    type Input = Warehouse;
+    type Answer = u64;
+
+    fn parse(input: &str) -> Self::Input {
+        parse_input(input)
+    }
+
+    fn part1(input: &Self::Input) -> u64 {
+        day15(build_input(input))
+    }
+
+    fn part2(input: &Self::Input) -> u64 {
+        day15_v2::part2(input)
     }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::solution::{solve, Part};
+    use crate::utils::EXAMPLE;
 
     #[test]
     fn test_example() {
-        assert_eq!(main("example").unwrap(), 10092);
+        assert_eq!(solve::<Day15>(EXAMPLE, Part::One).unwrap(), 10092);
     }
 }
@@ -1,6 +1,8 @@
-use std::{fmt, fs::read_to_string};
+use tracing::debug;
 
-use crate::utils::{get_test_file, Board, Direction, FileNotFound, ACTUAL, EXAMPLE};
+use crate::day15::Warehouse;
+use crate::solution::checked;
+use crate::utils::{Board, Direction, Tile};
 
 #[derive(Copy, Clone, Debug, PartialEq)]
 enum State {
@@ -22,16 +24,6 @@ impl State {
         }
     }
 
-    fn to_char(&self) -> char {
-        match self {
-            State::Wall => '#',
-            State::Empty => '.',
-            State::BoxLeft => '[',
-            State::BoxRight => ']',
-            State::Robot => '@',
-        }
-    }
-
     fn box_other_half_next_pos(&self, pos: (usize, usize)) -> (usize, usize) {
         match self {
             State::BoxRight => (pos.0, pos.1 - 1),
@@ -41,17 +33,15 @@ impl State {
     }
 }
 
-impl fmt::Display for Board<State> {
-    fn fmt(self: &Self, f: &mut fmt::Formatter) -> fmt::Result {
-        writeln!(
-            f,
-            concat!("Board:\n\t{}"),
-            self.board
-                .iter()
-                .map(|v| v.iter().map(|s| s.to_char()).collect())
-                .collect::<Vec<String>>()
-                .join("\n\t"),
-        )
+impl Tile for State {
+    fn to_char(&self) -> char {
+        match self {
+            State::Wall => '#',
+            State::Empty => '.',
+            State::BoxLeft => '[',
+            State::BoxRight => ']',
+            State::Robot => '@',
+        }
     }
 }
 
@@ -121,7 +111,7 @@ impl Input {
     fn push_box(&mut self, d: &Direction, pos: (usize, usize)) {
         let next_pos = self.board.add_direction(d, pos).unwrap();
         let next_state = self.board.get_pos(next_pos).unwrap();
-        let curr_state = self.board.get_pos(pos).unwrap().clone();
+        let curr_state = *self.board.get_pos(pos).unwrap();
 
         match *next_state {
             State::Empty => {
@@ -143,7 +133,7 @@ impl Input {
         }
     }
 
-    fn sum_box_gps(self) -> u32 {
+    fn sum_box_gps(self) -> u64 {
         let mut result = 0;
         for i in 0..self.board.len() {
             for j in 0..self.board[i].len() {
@@ -153,84 +143,57 @@ impl Input {
             }
         }
 
-        result as u32
+        checked(result)
     }
 }
 
-fn day15_v2(mut input: Input) -> u32 {
+fn day15_v2(mut input: Input) -> u64 {
     for d in input.moves.clone() {
         input.move_robot(&d);
     }
     input.sum_box_gps()
 }
 
-fn parse_input(filepath: &str) -> Input {
-    let mut board: Vec<Vec<State>> = vec![];
-    let mut moves: Vec<Direction> = vec![];
-
-    enum ReadingMode {
-        Board,
-        Moves,
-    }
-    let mut reading_mode = ReadingMode::Board;
-    read_to_string(filepath).unwrap().lines().for_each(|l| {
-        if l == "" {
-            reading_mode = ReadingMode::Moves;
-        } else {
-            match reading_mode {
-                ReadingMode::Board => {
-                    board.push(
-                        l.chars()
-                            .map(|c| State::from_char(c))
-                            .collect::<Vec<[State; 2]>>()
-                            .into_flattened(),
-                    );
-                }
-                ReadingMode::Moves => {
-                    l.chars().for_each(|c| moves.push(Direction::from_char(c)));
-                }
-            }
-        }
-    });
+fn build_input(warehouse: &Warehouse) -> Input {
+    let mut board: Vec<Vec<State>> = warehouse
+        .map
+        .iter()
+        .map(|l| {
+            l.iter()
+                .map(|c| State::from_char(*c))
+                .collect::<Vec<[State; 2]>>()
+                .into_flattened()
+        })
+        .collect();
 
     let mut robot_pos: (usize, usize) = (0, 0);
-    for i in 0..board.len() {
-        for j in 0..board[i].len() {
-            if board[i][j] == State::Robot {
-                robot_pos = (i, j);
-                board[i][j + 1] = State::Empty;
-            }
+    for (i, row) in board.iter_mut().enumerate() {
+        if let Some(j) = row.iter().position(|s| *s == State::Robot) {
+            robot_pos = (i, j);
+            row[j + 1] = State::Empty;
         }
     }
     let input = Input {
         board: Board::new(board),
         robot_pos,
-        moves,
+        moves: warehouse.moves.clone(),
     };
-    // println!("{}", input.board);
+    debug!("{}", input.board);
     input
 }
 
-pub fn main(s: &str) -> Result<u32, FileNotFound> {
-    match s {
-        "example_v2" => match get_test_file(EXAMPLE, "15") {
-            Err(err) => Err(err),
-            Ok(file) => Ok(day15_v2(parse_input(&file))),
-        },
-        "actual_v2" => match get_test_file(ACTUAL, "15") {
-            Err(err) => Err(err),
-            Ok(file) => Ok(day15_v2(parse_input(&file))),
-        },
-        _ => todo!(),
-    }
+pub fn part2(warehouse: &Warehouse) -> u64 {
+    day15_v2(build_input(warehouse))
 }
 
 #[cfg(test)]
 mod tests {
-    use super::*;
+    use crate::day15::Day15;
+    use crate::solution::{solve, Part};
+    use crate::utils::EXAMPLE;
 
     #[test]
     fn test_example_v2() {
-        assert_eq!(main("example_v2").unwrap(), 9021);
+        assert_eq!(solve::<Day15>(EXAMPLE, Part::Two).unwrap(), 9021);
     }
 }
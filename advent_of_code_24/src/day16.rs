@@ -1,7 +1,9 @@
-use std::{fmt, fs::read_to_string};
+use tracing::{debug, trace};
 
-use crate::utils::{get_test_file, Board, Direction, FileNotFound, ACTUAL, EXAMPLE};
+use crate::solution::Solution;
+use crate::utils::{Board, Direction, Tile};
 
+#[derive(Clone)]
 enum State {
     Start,
     End,
@@ -20,7 +22,9 @@ impl State {
             _ => panic!("Unexpected symbol in input"),
         }
     }
+}
 
+impl Tile for State {
     fn to_char(&self) -> char {
         match self {
             State::Wall => '#',
@@ -32,20 +36,6 @@ impl State {
     }
 }
 
-impl fmt::Display for Board<State> {
-    fn fmt(self: &Self, f: &mut fmt::Formatter) -> fmt::Result {
-        writeln!(
-            f,
-            concat!("Board:\n\t{}"),
-            self.board
-                .iter()
-                .map(|v| v.iter().map(|s| s.to_char()).collect())
-                .collect::<Vec<String>>()
-                .join("\n\t"),
-        )
-    }
-}
-
 #[derive(PartialEq)]
 struct Node {
     score: u32,
@@ -53,7 +43,8 @@ struct Node {
     dir: Direction,
 }
 
-struct Input {
+#[derive(Clone)]
+pub struct Input {
     board: Board<State>,
     start: (usize, usize),
     minimum_score: u32,
@@ -66,9 +57,8 @@ impl Input {
 
         for i in 0..board.len() {
             for j in 0..board[i].len() {
-                match Some(board.get_pos((i, j)).unwrap()) {
-                    Some(State::Start) => start = (i, j),
-                    _ => (),
+                if let Some(State::Start) = Some(board.get_pos((i, j)).unwrap()) {
+                    start = (i, j)
                 }
             }
         }
@@ -84,7 +74,7 @@ impl Input {
         if self.minimum_score > other {
             self.minimum_score = other;
 
-            // println!("Min: {}\n{}", self.minimum_score, self.board);
+            debug!("Min: {}\n{}", self.minimum_score, self.board);
         }
     }
     fn walk(&mut self, start: (usize, usize)) {
@@ -136,20 +126,20 @@ impl Input {
                 let curr_pos = lowest_scored_pos.pos;
                 let prev_dir = lowest_scored_pos.dir;
 
-                // println!("{}", self.board);
-                // println!("Curr pos:{:?}", curr_pos);
+                trace!("{}", self.board);
+                trace!("Curr pos:{:?}", curr_pos);
 
                 queue.remove(queue.iter().position(|x| x == lowest_scored_pos).unwrap());
 
                 for d in &Direction::ORTHOGONALS {
                     let next_pos = self.board.add_direction(d, curr_pos).unwrap();
                     let next_state = self.board.get_pos(next_pos).unwrap();
-                    // println!(
-                    //     "Dir: {:?}, Next pos: {:?}, Next state: {:?}",
-                    //     d,
-                    //     next_pos,
-                    //     next_state.to_char()
-                    // );
+                    trace!(
+                        "Dir: {:?}, Next pos: {:?}, Next state: {:?}",
+                        d,
+                        next_pos,
+                        next_state.to_char()
+                    );
 
                     match next_state {
                         State::Wall | State::Start => {}
@@ -188,7 +178,7 @@ impl Input {
                                 queue.push(Node {
                                     score: new_score,
                                     pos: next_pos,
-                                    dir: d.clone(),
+                                    dir: *d,
                                 });
 
                                 self.board
@@ -208,81 +198,78 @@ impl Input {
     }
 }
 
-fn day16(mut input: Input) -> u32 {
+fn day16(input: &Input) -> u32 {
+    let mut input = input.clone();
     input.walk(input.start);
     input.minimum_score
 }
 
-fn day16_v2(_input: Input) -> u32 {
+fn day16_v2(_input: &Input) -> u32 {
     let result: u32 = 0;
     result
 }
 
-fn parse_input(filepath: &str) -> Input {
+fn parse_input(input: &str) -> Input {
     let mut board: Vec<Vec<State>> = vec![];
 
-    read_to_string(filepath).unwrap().lines().for_each(|l| {
-        board.push(
-            l.chars()
-                .map(|c| State::from_char(c))
-                .collect::<Vec<State>>(),
-        );
+    input.lines().for_each(|l| {
+        board.push(l.chars().map(State::from_char).collect::<Vec<State>>());
     });
 
     Input::new(board)
 }
 
-pub fn main(s: &str) -> Result<u32, FileNotFound> {
-    match s {
-        "example" => match get_test_file(EXAMPLE, "16") {
-            Err(err) => Err(err),
-            Ok(file) => Ok(day16(parse_input(&file))),
-        },
-        "example2" => match get_test_file(EXAMPLE, "16_2") {
-            Err(err) => Err(err),
-            Ok(file) => Ok(day16(parse_input(&file))),
-        },
-        "actual" => match get_test_file(ACTUAL, "16") {
-            Err(err) => Err(err),
-            Ok(file) => Ok(day16(parse_input(&file))),
-        },
-        "example_v2" => match get_test_file(EXAMPLE, "16") {
-            Err(err) => Err(err),
-            Ok(file) => Ok(day16_v2(parse_input(&file))),
-        },
-        "example2_v2" => match get_test_file(EXAMPLE, "16_2") {
-            Err(err) => Err(err),
-            Ok(file) => Ok(day16_v2(parse_input(&file))),
-        },
-        "actual_v2" => match get_test_file(ACTUAL, "16") {
-            Err(err) => Err(err),
-            Ok(file) => Ok(day16_v2(parse_input(&file))),
-        },
-        _ => todo!(),
+pub struct Day16;
+
+impl Solution for Day16 {
+    const DAY: u32 = 16;
+    const TITLE: &'static str = "Reindeer Maze";
+
+    type Input = Input;
+    type Answer = u32;
+
+    fn parse(input: &str) -> Self::Input {
+        parse_input(input)
+    }
+
+    fn part1(input: &Self::Input) -> u32 {
+        day16(input)
+    }
+
+    fn part2(input: &Self::Input) -> u32 {
+        day16_v2(input)
     }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::solution::{solve, Part};
+    use crate::utils::{read_test_file, EXAMPLE};
 
     #[test]
     fn test_example_1() {
-        assert_eq!(main("example").unwrap(), 7036);
+        assert_eq!(solve::<Day16>(EXAMPLE, Part::One).unwrap(), 7036);
     }
 
     #[test]
     fn test_example_2() {
-        assert_eq!(main("example2").unwrap(), 11048);
+        assert_eq!(
+            Day16::part1(&Day16::parse(&read_test_file(EXAMPLE, "16_2").unwrap())),
+            11048
+        );
     }
 
     #[test]
     fn test_example_1_v2() {
-        assert_eq!(main("example_v2").unwrap(), 0);
+        assert_eq!(solve::<Day16>(EXAMPLE, Part::Two).unwrap(), 0);
     }
 
     #[test]
     fn test_example_2_v2() {
-        assert_eq!(main("example2_v2").unwrap(), 0);
+        assert_eq!(
+            Day16::part2(&Day16::parse(&read_test_file(EXAMPLE, "16_2").unwrap())),
+            0
+        );
     }
 }
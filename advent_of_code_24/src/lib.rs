@@ -0,0 +1,78 @@
+//! Advent of Code 2024 solutions, run with `aoc --year 2024`. Each `dayNN`
+//! module has a `DayNN` type implementing
+//! [`Solution`](solution::Solution), so a day can be parsed and solved directly:
+//!
+//! ```
+//! use advent_of_code_24::day01::Day01;
+//! use advent_of_code_24::solution::Solution;
+//!
+//! let input = Day01::parse("3   4\n4   3\n2   5\n1   3\n3   9\n3   3\n");
+//! assert_eq!(Day01::part1(&input), 11);
+//! ```
+//!
+//! [`SOLUTIONS`] has every implemented day behind the object-safe
+//! [`DynSolution`]. The shared `solution` and `utils` modules come from `aoc_core`.
+
+#[cfg(test)]
+mod day00;
+pub mod day01;
+pub mod day02;
+pub mod day03;
+pub mod day03_failed;
+pub mod day04;
+pub mod day05;
+pub mod day06;
+pub mod day07;
+pub mod day08;
+pub mod day09;
+pub mod day10;
+pub mod day11;
+pub mod day12;
+pub mod day13;
+pub mod day14;
+pub mod day15;
+pub mod day15_v2;
+pub mod day16;
+// pub mod day17;
+// pub mod day18;
+// pub mod day19;
+// pub mod day20;
+// pub mod day21;
+// pub mod day22;
+// pub mod day23;
+// pub mod day24;
+// pub mod day25;
+
+// The days are written against these, as when they lived in this crate
+pub use aoc_core::{solution, utils};
+
+use aoc_core::solution::{DynSolution, Year};
+
+/// Every implemented day, in day order
+pub const SOLUTIONS: &[&dyn DynSolution] = &[
+    &day01::Day01,
+    &day02::Day02,
+    &day03::Day03,
+    &day04::Day04,
+    &day05::Day05,
+    &day06::Day06,
+    &day07::Day07,
+    &day08::Day08,
+    &day09::Day09,
+    &day10::Day10,
+    &day11::Day11,
+    &day12::Day12,
+    &day13::Day13,
+    &day14::Day14,
+    &day15::Day15,
+    &day16::Day16,
+];
+
+/// The 2024 solutions, as registered with the `aoc` runner
+pub const YEAR: Year = Year {
+    year: 2024,
+    dir: "advent_of_code_24",
+    manifest_dir: env!("CARGO_MANIFEST_DIR"),
+    target: module_path!(),
+    solutions: SOLUTIONS,
+};
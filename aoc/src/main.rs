@@ -0,0 +1,98 @@
+use std::env::{current_dir, set_current_dir};
+use std::path::{Path, PathBuf};
+use std::process::ExitCode;
+
+use clap::Parser;
+
+use aoc_core::alloc::CountingAlloc;
+use aoc_core::cli::{Cli, Command};
+use aoc_core::solution::Year;
+use aoc_core::{commands, logging};
+
+#[global_allocator]
+static GLOBAL: CountingAlloc = CountingAlloc;
+
+/// Every year with solutions, oldest first
+const YEARS: &[Year] = &[advent_of_code_24::YEAR];
+
+fn find_year(year: Option<u32>) -> Result<&'static Year, String> {
+    match year {
+        None => YEARS.last().ok_or("No years are registered".to_string()),
+        Some(year) => YEARS.iter().find(|y| y.year == year).ok_or(format!(
+            "No solutions for {year}, the years are {}",
+            YEARS
+                .iter()
+                .map(|y| y.year.to_string())
+                .collect::<Vec<_>>()
+                .join(", ")
+        )),
+    }
+}
+
+/// The folder `year` keeps its inputs, answers and benchmarks in: `dir` when
+/// given, otherwise the year's crate in the workspace around the current
+/// directory. Falls back to where the crate was built from, so `cargo run`
+/// still works from anywhere on a dev checkout.
+fn find_dir(year: &Year, dir: Option<PathBuf>) -> Result<PathBuf, String> {
+    if let Some(dir) = dir {
+        return Ok(dir);
+    }
+    let cwd = current_dir().map_err(|e| format!("Could not read the current directory: {e}"))?;
+    if let Some(dir) = cwd
+        .ancestors()
+        .map(|root| root.join(year.dir))
+        .find(|dir| dir.join("Cargo.toml").is_file())
+    {
+        return Ok(dir);
+    }
+    let fallback = Path::new(year.manifest_dir);
+    if fallback.is_dir() {
+        return Ok(fallback.to_path_buf());
+    }
+    Err(format!(
+        "Could not find {} from {}, run from the workspace or pass --dir",
+        year.dir,
+        cwd.display()
+    ))
+}
+
+fn main() -> ExitCode {
+    let cli = Cli::parse();
+    let year = match find_year(cli.year) {
+        Ok(year) => year,
+        Err(err) => {
+            eprintln!("{err}");
+            return ExitCode::FAILURE;
+        }
+    };
+    let dir = match find_dir(year, cli.dir.clone()) {
+        Ok(dir) => dir,
+        Err(err) => {
+            eprintln!("{err}");
+            return ExitCode::FAILURE;
+        }
+    };
+    // Inputs, answers and benchmarks are kept per year, relative to its crate
+    if let Err(e) = set_current_dir(&dir) {
+        eprintln!("Could not open {}: {e}", dir.display());
+        return ExitCode::FAILURE;
+    }
+    logging::init(year.target, cli.verbose, &cli.log_days);
+    let limits = cli.limits();
+    let sets_limits = cli.sets_limits();
+    match cli.command {
+        None => {
+            commands::menu(year, limits);
+            ExitCode::SUCCESS
+        }
+        Some(Command::Run(args)) => commands::run(&args, year, limits),
+        Some(Command::Bench(args)) => commands::bench(&args, year, sets_limits),
+        Some(Command::Compare(args)) => commands::compare(&args),
+        Some(Command::NewDay(args)) => commands::new_day(&args),
+        Some(Command::Extract(args)) => commands::extract(&args),
+        Some(Command::Fetch(args)) => commands::fetch(&args, year),
+        Some(Command::Submit(args)) => commands::submit(&args, year, limits),
+        Some(Command::Serve(args)) => commands::serve(&args, year, limits),
+        Some(Command::Worker(args)) => commands::worker(&args, year, cli.memory_limit),
+    }
+}
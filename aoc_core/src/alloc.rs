@@ -0,0 +1,158 @@
+use std::alloc::{GlobalAlloc, Layout, System};
+use std::cell::Cell;
+use std::fmt;
+
+use serde::{Deserialize, Serialize};
+
+/// Wraps the system allocator to count allocations and track the peak heap
+/// use of each thread, so one part's usage isn't mixed up with another's.
+/// `measure` only sees allocations when this is the `#[global_allocator]`.
+pub struct CountingAlloc;
+
+// The binary installs it too, the library leaves the choice to its users
+#[cfg(test)]
+#[global_allocator]
+static GLOBAL: CountingAlloc = CountingAlloc;
+
+#[derive(Clone, Copy)]
+struct Counters {
+    // Signed since a thread can free memory another one allocated
+    current: isize,
+    peak: isize,
+    allocations: usize,
+}
+
+thread_local! {
+    // Const-initialized without a destructor, so it never allocates itself
+    static COUNTERS: Cell<Counters> = const {
+        Cell::new(Counters {
+            current: 0,
+            peak: 0,
+            allocations: 0,
+        })
+    };
+}
+
+fn record(change: isize, allocation: bool) {
+    // Fails while the thread is being torn down, those frees aren't interesting
+    let _ = COUNTERS.try_with(|counters| {
+        let mut c = counters.get();
+        c.current += change;
+        c.peak = c.peak.max(c.current);
+        c.allocations += usize::from(allocation);
+        counters.set(c);
+    });
+}
+
+unsafe impl GlobalAlloc for CountingAlloc {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        let ptr = System.alloc(layout);
+        if !ptr.is_null() {
+            record(layout.size() as isize, true);
+        }
+        ptr
+    }
+
+    unsafe fn alloc_zeroed(&self, layout: Layout) -> *mut u8 {
+        let ptr = System.alloc_zeroed(layout);
+        if !ptr.is_null() {
+            record(layout.size() as isize, true);
+        }
+        ptr
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        System.dealloc(ptr, layout);
+        record(-(layout.size() as isize), false);
+    }
+
+    unsafe fn realloc(&self, ptr: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+        let new_ptr = System.realloc(ptr, layout, new_size);
+        if !new_ptr.is_null() {
+            record(new_size as isize - layout.size() as isize, true);
+        }
+        new_ptr
+    }
+}
+
+/// Heap used while running something on the current thread
+#[derive(Clone, Copy, Default, PartialEq, Debug, Serialize, Deserialize)]
+pub struct HeapUsage {
+    /// Most bytes allocated at once, on top of what was allocated before
+    pub peak_bytes: usize,
+    pub allocations: usize,
+}
+
+/// Runs `f` and reports the heap it used on this thread
+pub fn measure<T>(f: impl FnOnce() -> T) -> (T, HeapUsage) {
+    let before = COUNTERS.with(|counters| {
+        let mut c = counters.get();
+        c.peak = c.current;
+        counters.set(c);
+        c
+    });
+    let result = f();
+    let after = COUNTERS.with(|counters| counters.get());
+    let usage = HeapUsage {
+        peak_bytes: (after.peak - before.current).max(0) as usize,
+        allocations: after.allocations - before.allocations,
+    };
+    (result, usage)
+}
+
+/// Formats a number of bytes with a binary unit, e.g. `1.50 KiB`
+pub struct Bytes(pub usize);
+
+impl fmt::Display for Bytes {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        const UNITS: [&str; 4] = ["KiB", "MiB", "GiB", "TiB"];
+        if self.0 < 1024 {
+            return write!(f, "{} B", self.0);
+        }
+        let mut size = self.0 as f64 / 1024.0;
+        let mut unit = 0;
+        while size >= 1024.0 && unit < UNITS.len() - 1 {
+            size /= 1024.0;
+            unit += 1;
+        }
+        write!(f, "{size:.2} {}", UNITS[unit])
+    }
+}
+
+impl fmt::Display for HeapUsage {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "peak {}, {} allocations",
+            Bytes(self.peak_bytes),
+            self.allocations
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_measure() {
+        let (v, usage) = measure(|| vec![0u8; 4096]);
+        assert_eq!(usage.allocations, 1);
+        assert_eq!(usage.peak_bytes, 4096);
+        drop(v);
+
+        let (_, usage) = measure(|| {
+            drop(vec![0u8; 1000]);
+            drop(vec![0u8; 1000]);
+        });
+        assert_eq!(usage.allocations, 2);
+        assert_eq!(usage.peak_bytes, 1000);
+    }
+
+    #[test]
+    fn test_bytes() {
+        assert_eq!(Bytes(512).to_string(), "512 B");
+        assert_eq!(Bytes(1536).to_string(), "1.50 KiB");
+        assert_eq!(Bytes(3 * 1024 * 1024).to_string(), "3.00 MiB");
+    }
+}
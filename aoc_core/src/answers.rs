@@ -0,0 +1,176 @@
+use std::collections::HashMap;
+use std::fmt;
+use std::fs::read_to_string;
+use std::io::ErrorKind;
+
+use serde::{Deserialize, Serialize};
+
+use crate::runner::{InputKind, InputRun, Outcome};
+use crate::solution::Part;
+use crate::utils::{ACTUAL, EXAMPLE};
+
+/// Known answers for one input kind, read from `answers.toml` next to the
+/// input files, e.g. `.tests/answers.toml`:
+///
+/// ```toml
+/// [day01]
+/// part1 = 11
+/// part2 = 31
+/// ```
+///
+/// Answers that don't fit an integer can be written as strings.
+#[derive(Deserialize, Default)]
+struct Answers(HashMap<String, DayAnswers>);
+
+#[derive(Deserialize, Default)]
+struct DayAnswers {
+    part1: Option<Expected>,
+    part2: Option<Expected>,
+}
+
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum Expected {
+    Int(i64),
+    Str(String),
+}
+
+impl fmt::Display for Expected {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Expected::Int(n) => write!(f, "{n}"),
+            Expected::Str(s) => write!(f, "{s}"),
+        }
+    }
+}
+
+#[derive(Clone, PartialEq, Debug, Serialize, Deserialize)]
+pub enum Verdict {
+    Correct,
+    Wrong { expected: String },
+    Unknown,
+}
+
+impl Verdict {
+    pub fn status(&self) -> &'static str {
+        match self {
+            Verdict::Correct => "correct",
+            Verdict::Wrong { .. } => "WRONG",
+            Verdict::Unknown => "unknown",
+        }
+    }
+}
+
+impl fmt::Display for Verdict {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Verdict::Wrong { expected } => write!(f, "WRONG, expected {expected}"),
+            other => write!(f, "{}", other.status()),
+        }
+    }
+}
+
+pub struct AnswersError {
+    path: String,
+    message: String,
+}
+
+impl fmt::Display for AnswersError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "could not read {}: {}", self.path, self.message)
+    }
+}
+
+impl Answers {
+    /// Reads the answers for the inputs in `dir`. A missing file just means no
+    /// answers are known yet.
+    fn load(dir: &str) -> Result<Answers, AnswersError> {
+        let path = format!("./{dir}/answers.toml");
+        let error = |message: String| AnswersError {
+            path: path.clone(),
+            message,
+        };
+        match read_to_string(&path) {
+            Ok(text) => toml::from_str(&text).map_err(|e| error(e.message().to_string())),
+            Err(e) if e.kind() == ErrorKind::NotFound => Ok(Answers::default()),
+            Err(e) => Err(error(e.to_string())),
+        }
+    }
+
+    fn check(&self, day: u32, part: Part, answer: &str) -> Verdict {
+        let expected = self
+            .0
+            .get(&format!("day{day:02}"))
+            .and_then(|d| match part {
+                Part::One => d.part1.as_ref(),
+                Part::Two => d.part2.as_ref(),
+            });
+        match expected {
+            Some(expected) if expected.to_string() == answer => Verdict::Correct,
+            Some(expected) => Verdict::Wrong {
+                expected: expected.to_string(),
+            },
+            None => Verdict::Unknown,
+        }
+    }
+
+    fn verify(&self, run: &mut InputRun) {
+        for part_run in &mut run.parts {
+            if let Outcome::Solved(answer) = &part_run.outcome {
+                part_run.verdict = self.check(run.day, part_run.part, &answer.to_string());
+            }
+        }
+    }
+}
+
+/// Answers for both input kinds
+#[derive(Default)]
+pub struct KnownAnswers {
+    example: Answers,
+    actual: Answers,
+}
+
+impl KnownAnswers {
+    pub fn load() -> Result<KnownAnswers, AnswersError> {
+        Ok(KnownAnswers {
+            example: Answers::load(EXAMPLE)?,
+            actual: Answers::load(ACTUAL)?,
+        })
+    }
+
+    /// Fills in the verdict of every solved part of `run`. Answers are only
+    /// known for the fixture inputs.
+    pub fn verify(&self, run: &mut InputRun) {
+        match run.input {
+            InputKind::Example => self.example.verify(run),
+            InputKind::Actual => self.actual.verify(run),
+            InputKind::File(_) | InputKind::Stdin => {}
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_check() {
+        let answers: Answers = toml::from_str(
+            r#"
+            [day01]
+            part1 = 11
+            part2 = "31"
+            "#,
+        )
+        .unwrap();
+        assert_eq!(answers.check(1, Part::One, "11"), Verdict::Correct);
+        assert_eq!(answers.check(1, Part::Two, "31"), Verdict::Correct);
+        assert_eq!(
+            answers.check(1, Part::One, "12"),
+            Verdict::Wrong {
+                expected: "11".to_string()
+            }
+        );
+        assert_eq!(answers.check(2, Part::One, "2"), Verdict::Unknown);
+    }
+}
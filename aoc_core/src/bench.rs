@@ -0,0 +1,174 @@
+use std::any::Any;
+use std::panic::{catch_unwind, AssertUnwindSafe};
+use std::time::{Duration, Instant};
+
+use crate::report::print_columns;
+use crate::runner::{panic_message, InputKind, Outcome};
+use crate::solution::{DynSolution, Part};
+
+const HEADERS: [&str; 5] = ["", "Min", "Median", "Mean", "p95"];
+
+/// Summary of a set of timings
+#[derive(Debug, PartialEq)]
+pub struct Stats {
+    pub min: Duration,
+    pub median: Duration,
+    pub mean: Duration,
+    pub p95: Duration,
+}
+
+impl Stats {
+    pub fn from_samples(samples: &[Duration]) -> Stats {
+        assert!(!samples.is_empty(), "Need at least one sample");
+        let mut sorted = samples.to_vec();
+        sorted.sort();
+        let n = sorted.len();
+
+        let median = if n.is_multiple_of(2) {
+            (sorted[n / 2 - 1] + sorted[n / 2]) / 2
+        } else {
+            sorted[n / 2]
+        };
+        // Nearest-rank percentile
+        let p95 = sorted[(n * 95).div_ceil(100) - 1];
+
+        Stats {
+            min: sorted[0],
+            median,
+            mean: sorted.iter().sum::<Duration>() / n as u32,
+            p95,
+        }
+    }
+}
+
+pub struct Bench {
+    pub warmup: usize,
+    pub runs: u32,
+    pub variant: Option<String>,
+}
+
+/// Times `f` `runs` times after `warmup` untimed calls, stopping at the first panic
+fn sample<T>(bench: &Bench, f: impl Fn() -> T) -> Result<(T, Stats), Outcome> {
+    let call =
+        || catch_unwind(AssertUnwindSafe(&f)).map_err(|p| Outcome::Panicked(panic_message(p)));
+
+    for _ in 0..bench.warmup {
+        call()?;
+    }
+    let mut samples = Vec::with_capacity(bench.runs as usize);
+    let mut last = None;
+    for _ in 0..bench.runs {
+        let now = Instant::now();
+        let result = call()?;
+        samples.push(now.elapsed());
+        last = Some(result);
+    }
+    Ok((
+        last.expect("At least one run"),
+        Stats::from_samples(&samples),
+    ))
+}
+
+fn print_rows(rows: &[(String, Result<Stats, Outcome>)]) {
+    let cells: Vec<[String; 5]> = rows
+        .iter()
+        .map(|(name, stats)| {
+            let [min, median, mean, p95] = match stats {
+                Ok(s) => [s.min, s.median, s.mean, s.p95].map(|d| format!("{:.2?}", d)),
+                Err(_) => ["-", "-", "-", "-"].map(String::from),
+            };
+            [name.clone(), min, median, mean, p95]
+        })
+        .collect();
+
+    print_columns("\t", HEADERS, &cells, &[1, 2, 3, 4]);
+    for (name, stats) in rows {
+        if let Err(err) = stats {
+            println!("\t{name}: {err}");
+        }
+    }
+}
+
+/// Benchmarks parsing and each of `parts` on one input of a day and prints the
+/// timings. Returns the stats of each step, or the outcome of the first failure.
+pub fn bench_input(
+    bench: &Bench,
+    solution: &dyn DynSolution,
+    input: &InputKind,
+    parts: &[Part],
+) -> Result<Vec<(String, Stats)>, Outcome> {
+    let text = input.read(solution.day()).map_err(Outcome::Missing)?;
+
+    println!("{} ({} warm-up, {} runs)", input, bench.warmup, bench.runs);
+    let mut rows: Vec<(String, Result<Stats, Outcome>)> = vec![];
+    let parsed: Box<dyn Any> = match sample(bench, || {
+        solution.parse(&text, *input == InputKind::Example)
+    }) {
+        Ok((parsed, stats)) => {
+            rows.push(("Parse".to_string(), Ok(stats)));
+            parsed
+        }
+        Err(err) => {
+            rows.push(("Parse".to_string(), Err(err.clone())));
+            print_rows(&rows);
+            return Err(err);
+        }
+    };
+
+    let mut failure = None;
+    for part in parts {
+        // The variant is part of the name so its timings are saved apart
+        let name = match &bench.variant {
+            Some(variant) => format!("Part {} ({variant})", part.number()),
+            None => format!("Part {}", part.number()),
+        };
+        let stats = sample(bench, || {
+            solution.solve(parsed.as_ref(), *part, bench.variant.as_deref())
+        })
+        .map(|(_, s)| s);
+        if let Err(err) = &stats {
+            failure.get_or_insert(err.clone());
+        }
+        rows.push((name, stats));
+    }
+    print_rows(&rows);
+
+    match failure {
+        Some(err) => Err(err),
+        None => Ok(rows
+            .into_iter()
+            .filter_map(|(name, stats)| Some((name, stats.ok()?)))
+            .collect()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn ms(values: &[u64]) -> Vec<Duration> {
+        values.iter().map(|v| Duration::from_millis(*v)).collect()
+    }
+
+    #[test]
+    fn test_stats() {
+        let stats = Stats::from_samples(&ms(&[5, 1, 4, 2, 3]));
+        assert_eq!(
+            stats,
+            Stats {
+                min: Duration::from_millis(1),
+                median: Duration::from_millis(3),
+                mean: Duration::from_millis(3),
+                p95: Duration::from_millis(5),
+            }
+        );
+    }
+
+    #[test]
+    fn test_stats_even() {
+        let samples = ms(&(1..=20).collect::<Vec<u64>>());
+        let stats = Stats::from_samples(&samples);
+        assert_eq!(stats.median, Duration::from_micros(10_500));
+        assert_eq!(stats.p95, Duration::from_millis(19));
+    }
+}
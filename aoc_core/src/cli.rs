@@ -0,0 +1,342 @@
+use std::path::{absolute, PathBuf};
+use std::time::Duration;
+
+use clap::{ArgAction, Args, Parser, Subcommand, ValueEnum};
+
+use crate::runner::InputKind;
+use crate::solution::Part;
+use crate::worker::Limits;
+
+/// Advent of Code solutions. Starts the interactive menu when no command is given.
+#[derive(Parser)]
+#[command(version, about)]
+pub struct Cli {
+    #[command(subcommand)]
+    pub command: Option<Command>,
+
+    /// Year to run, defaults to the latest one
+    #[arg(short, long, global = true)]
+    pub year: Option<u32>,
+
+    /// The year's folder, found in the workspace around the current directory by default
+    #[arg(long, global = true)]
+    pub dir: Option<PathBuf>,
+
+    /// Seconds each part may run before it is stopped, 0 for no limit [default: 60]
+    #[arg(long, global = true, value_parser = timeout_secs)]
+    pub timeout: Option<f64>,
+
+    /// Memory each part may use, in MiB
+    #[arg(long, global = true)]
+    pub memory_limit: Option<u64>,
+
+    /// Print the solutions' debug events to stderr, -vv for trace events too
+    #[arg(short, long, global = true, action = ArgAction::Count)]
+    pub verbose: u8,
+
+    /// Only print events from this day, can be repeated
+    #[arg(
+        long = "log-day",
+        global = true,
+        value_parser = clap::value_parser!(u32).range(1..=25)
+    )]
+    pub log_days: Vec<u32>,
+}
+
+const DEFAULT_TIMEOUT: f64 = 60.0;
+
+// Anything `Duration` can't hold is refused here instead of panicking later
+fn timeout_secs(arg: &str) -> Result<f64, String> {
+    let secs: f64 = arg.parse().map_err(|e| format!("{e}"))?;
+    Duration::try_from_secs_f64(secs)
+        .map(|_| secs)
+        .map_err(|_| "expected a number of seconds from 0 up to about 1.8e19".to_string())
+}
+
+impl Cli {
+    pub fn limits(&self) -> Limits {
+        let timeout = self.timeout.unwrap_or(DEFAULT_TIMEOUT);
+        Limits {
+            timeout: (timeout > 0.0).then(|| Duration::from_secs_f64(timeout)),
+            memory_mb: self.memory_limit,
+        }
+    }
+
+    /// Whether a limit was asked for with --timeout or --memory-limit, for the
+    /// commands that can't enforce them
+    pub fn sets_limits(&self) -> bool {
+        self.timeout.is_some_and(|t| t > 0.0) || self.memory_limit.is_some()
+    }
+}
+
+#[derive(Subcommand)]
+pub enum Command {
+    /// Run a single day, or every implemented day with --all and print a summary table
+    Run(RunArgs),
+    /// Time parsing and solving over many runs and report min/median/mean/p95
+    Bench(BenchArgs),
+    /// Compare saved benchmarks of two commits and flag regressions
+    Compare(CompareArgs),
+    /// Create and register a new day from the day00 template
+    NewDay(NewDayArgs),
+    /// Save the example and its answers from a puzzle page saved from the browser
+    Extract(ExtractArgs),
+    /// Download a day's input, unless it was downloaded already
+    Fetch(FetchArgs),
+    /// Submit a day's answer for the actual input, refusing answers already known to be wrong
+    Submit(SubmitArgs),
+    /// Answer `POST /solve/{day}/{part}` requests, with the input as the body, over HTTP
+    Serve(ServeArgs),
+    /// Solves one part in this process and prints the run as JSON, used by
+    /// the runner to enforce --timeout and --memory-limit
+    #[command(hide = true)]
+    Worker(WorkerArgs),
+}
+
+#[derive(Args)]
+pub struct RunArgs {
+    #[command(flatten)]
+    pub select: Selection,
+
+    /// Number of inputs to run at the same time. Timings get noisier above 1
+    #[arg(
+        short,
+        long,
+        default_value_t = 1,
+        value_parser = clap::builder::RangedU64ValueParser::<usize>::new().range(1..)
+    )]
+    pub jobs: usize,
+
+    /// Run again every time the day's input files change
+    #[arg(short, long, conflicts_with_all = ["all", "cross_check", "format"])]
+    pub watch: bool,
+
+    /// Run every variant of each part and report any that disagree
+    #[arg(long, conflicts_with_all = ["variant", "format"])]
+    pub cross_check: bool,
+
+    /// How to print the results
+    #[arg(short, long, value_enum, default_value_t = Format::Text)]
+    pub format: Format,
+}
+
+#[derive(Clone, Copy, PartialEq, ValueEnum)]
+pub enum Format {
+    /// Readable output, a table with --all
+    Text,
+    /// A JSON array with one record per part
+    Json,
+    /// One CSV row per part, with a header
+    Csv,
+}
+
+#[derive(Args)]
+pub struct BenchArgs {
+    #[command(flatten)]
+    pub select: Selection,
+
+    /// Untimed runs before measuring
+    #[arg(long, default_value_t = 3)]
+    pub warmup: usize,
+
+    /// Timed runs of the parser and of each part
+    #[arg(
+        short,
+        long,
+        default_value_t = 20,
+        value_parser = clap::value_parser!(u32).range(1..)
+    )]
+    pub runs: u32,
+
+    /// Don't append the timings to the benchmark history
+    #[arg(long)]
+    pub no_save: bool,
+}
+
+#[derive(Args)]
+pub struct CompareArgs {
+    /// Commit to compare against, as saved by `bench` (a prefix is enough)
+    pub baseline: String,
+
+    /// Commit to compare, defaults to the most recently saved one
+    #[arg(long)]
+    pub against: Option<String>,
+
+    /// Slowdown of the median time, in percent, that counts as a regression
+    #[arg(short, long, default_value_t = 10.0)]
+    pub threshold: f64,
+}
+
+#[derive(Args)]
+pub struct NewDayArgs {
+    /// Day to create, from 1 to 25
+    #[arg(value_parser = clap::value_parser!(u32).range(1..=25))]
+    pub day: u32,
+}
+
+#[derive(Args)]
+pub struct ExtractArgs {
+    /// Day the page belongs to, from 1 to 25
+    #[arg(short, long, value_parser = clap::value_parser!(u32).range(1..=25))]
+    pub day: u32,
+
+    /// The saved puzzle page
+    #[arg(value_parser = absolute_path)]
+    pub page: PathBuf,
+
+    /// Which `<pre><code>` block of the page is the example input, counting from 1
+    #[arg(
+        short,
+        long,
+        default_value_t = 1,
+        value_parser = clap::builder::RangedU64ValueParser::<usize>::new().range(1..)
+    )]
+    pub example: usize,
+
+    /// Replace an example or answers that differ from the page
+    #[arg(long)]
+    pub force: bool,
+}
+
+// Paths given on the command line are made absolute before the binary moves
+// to the year's directory
+fn absolute_path(arg: &str) -> Result<PathBuf, String> {
+    absolute(arg).map_err(|e| e.to_string())
+}
+
+#[derive(Args)]
+pub struct FetchArgs {
+    /// Day to download, from 1 to 25
+    #[arg(short, long, value_parser = clap::value_parser!(u32).range(1..=25))]
+    pub day: u32,
+}
+
+#[derive(Args)]
+pub struct SubmitArgs {
+    /// Day to submit, from 1 to 25
+    #[arg(short, long, value_parser = clap::value_parser!(u32).range(1..=25))]
+    pub day: u32,
+
+    /// Part to submit (1 or 2)
+    #[arg(short, long, value_parser = clap::value_parser!(u8).range(1..=2))]
+    pub part: u8,
+
+    /// Submit this answer instead of solving the actual input
+    #[arg(long)]
+    pub answer: Option<String>,
+}
+
+#[derive(Args)]
+pub struct ServeArgs {
+    /// Port to listen on
+    #[arg(short, long, default_value_t = 8080)]
+    pub port: u16,
+
+    /// Address to listen on, use 0.0.0.0 to accept requests from other machines
+    #[arg(long, default_value = "127.0.0.1")]
+    pub host: String,
+
+    /// Number of connections handled at the same time, others wait for a free one
+    #[arg(
+        short,
+        long,
+        default_value_t = 4,
+        value_parser = clap::builder::RangedU64ValueParser::<usize>::new().range(1..)
+    )]
+    pub jobs: usize,
+}
+
+#[derive(Args)]
+pub struct WorkerArgs {
+    #[arg(long)]
+    pub day: u32,
+
+    #[arg(long)]
+    pub input: InputKind,
+
+    /// The input's text comes on stdin instead of from its file
+    #[arg(long)]
+    pub text: bool,
+
+    #[arg(long, value_parser = clap::value_parser!(u8).range(1..=2))]
+    pub part: u8,
+
+    #[arg(long)]
+    pub variant: Option<String>,
+}
+
+/// Which days, parts and inputs a command works on
+#[derive(Args)]
+pub struct Selection {
+    /// Day to run, from 1 to 25
+    #[arg(
+        short,
+        long,
+        value_parser = clap::value_parser!(u32).range(1..=25),
+        required_unless_present = "all"
+    )]
+    pub day: Option<u32>,
+
+    /// Run every implemented day
+    #[arg(short, long, conflicts_with = "day")]
+    pub all: bool,
+
+    /// Only run this part (1 or 2)
+    #[arg(short, long, value_parser = clap::value_parser!(u8).range(1..=2))]
+    pub part: Option<u8>,
+
+    /// Only run this input: `example`, `actual`, a file path, or `-` for stdin
+    #[arg(short, long)]
+    pub input: Option<InputKind>,
+
+    /// Only run the example input, same as `--input example`
+    #[arg(long, conflicts_with = "input")]
+    pub example_only: bool,
+
+    /// Use this implementation of each part instead of the default one
+    #[arg(long, conflicts_with = "all")]
+    pub variant: Option<String>,
+}
+
+impl Selection {
+    pub fn parts(&self) -> Vec<Part> {
+        match self.part {
+            Some(1) => vec![Part::One],
+            Some(_) => vec![Part::Two],
+            None => vec![Part::One, Part::Two],
+        }
+    }
+
+    pub fn inputs(&self) -> Vec<InputKind> {
+        match (&self.input, self.example_only) {
+            (Some(input), _) => vec![input.clone()],
+            (None, true) => vec![InputKind::Example],
+            (None, false) => InputKind::INPUTS.to_vec(),
+        }
+    }
+
+    // A missing input file only counts as a failure when it was asked for explicitly
+    pub fn input_required(&self) -> bool {
+        self.input.is_some() || self.example_only
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_timeout() {
+        let limits = |timeout: &str| {
+            Cli::try_parse_from(["aoc", "--timeout", timeout]).map(|cli| cli.limits().timeout)
+        };
+        assert_eq!(limits("2.5").unwrap(), Some(Duration::from_millis(2500)));
+        assert_eq!(limits("0").unwrap(), None);
+        let cli = Cli::try_parse_from(["aoc"]).unwrap();
+        assert_eq!(cli.limits().timeout, Some(Duration::from_secs(60)));
+        assert!(!cli.sets_limits());
+        for timeout in ["1e300", "NaN", "inf", "-1", "soon"] {
+            assert!(limits(timeout).is_err(), "{timeout}");
+        }
+    }
+}
@@ -0,0 +1,562 @@
+//! The commands of the `aoc` binary
+
+use std::io::{stdin, Read};
+use std::path::PathBuf;
+use std::process::ExitCode;
+use std::time::Instant;
+
+use crate::answers::{KnownAnswers, Verdict};
+use crate::bench::{bench_input, Bench};
+use crate::cli::{
+    BenchArgs, CompareArgs, ExtractArgs, FetchArgs, Format, NewDayArgs, RunArgs, Selection,
+    ServeArgs, SubmitArgs, WorkerArgs,
+};
+use crate::config::Config;
+use crate::fetch::Fetched;
+use crate::history::Entry;
+use crate::runner::{run_input, run_parallel, run_text, InputKind, InputRun, Outcome};
+use crate::solution::{DynSolution, Part, Year};
+use crate::submit::{Guess, GuessLog};
+use crate::worker::{cross_check_isolated, run_input_isolated, set_memory_limit, Limits};
+
+use crate::{extract, fetch, history, report, runner, scaffold, serve, submit, watch};
+
+fn print_header(solution: &dyn DynSolution) {
+    println!("Day {}: {}", solution.day(), solution.title());
+}
+
+fn execute(
+    year: &Year,
+    solution: &dyn DynSolution,
+    input: &InputKind,
+    parts: &[Part],
+    variant: Option<&str>,
+    limits: Limits,
+) -> InputRun {
+    if limits.is_unlimited() {
+        run_input(solution, input, parts, variant)
+    } else {
+        run_input_isolated(year.year, solution, input, parts, variant, limits)
+    }
+}
+
+fn print_input_run(run: &InputRun) {
+    if let Ok(parse_time) = run.parse {
+        println!("{} parsed", run.input.label(Part::One));
+        println!("\tElapsed: {:.2?}", parse_time);
+    }
+    for part_run in &run.parts {
+        let name = run.input.label(part_run.part);
+        if let Outcome::Solved(_) = part_run.outcome {
+            println!("{name}: {} ({})", part_run.outcome, part_run.verdict);
+            println!("\tElapsed: {:.2?}", part_run.elapsed);
+            println!("\tHeap: {}", part_run.heap);
+        } else {
+            println!("{name}: {}", part_run.outcome);
+        }
+    }
+}
+
+fn run_inputs(year: &Year, solution: &dyn DynSolution, answers: &KnownAnswers, limits: Limits) {
+    for input in InputKind::INPUTS {
+        let mut run = execute(
+            year,
+            solution,
+            &input,
+            &[Part::One, Part::Two],
+            None,
+            limits,
+        );
+        answers.verify(&mut run);
+        print_input_run(&run);
+    }
+    println!();
+}
+
+pub fn menu(year: &Year, limits: Limits) {
+    let answers = KnownAnswers::load().unwrap_or_else(|err| {
+        println!("WARNING! {err}, answers won't be checked.");
+        KnownAnswers::default()
+    });
+    println!("Advent of Code {}", year.year);
+    loop {
+        let mut input = String::new();
+        println!(
+            "Choose a Day from {} to {}; 0 exits and input defaults to 0.",
+            1, 25
+        );
+        let input = match stdin().read_line(&mut input) {
+            Ok(_) => input,
+            Err(_) => panic!("How did you even do this"),
+        }
+        .trim()
+        .parse::<u32>()
+        .unwrap_or(0);
+
+        match input {
+            0 => break,
+            1..=25 => match year.find_solution(input) {
+                Some(solution) => {
+                    print_header(solution);
+                    run_inputs(year, solution, &answers, limits)
+                }
+                None => println!("not yet implemented"),
+            },
+            _ => println!("command not found"),
+        }
+    }
+    println!("Bye!");
+}
+
+// Resolves `--day`/`--all`, printing an error for days that are not implemented yet
+fn select_solutions(year: &Year, select: &Selection) -> Option<Vec<&'static dyn DynSolution>> {
+    if select.all && select.input.as_ref().is_some_and(|i| !i.is_fixture()) {
+        eprintln!("A file or stdin input belongs to a single day, use --day instead of --all");
+        return None;
+    }
+    let solutions = match select.day {
+        Some(day) => match year.find_solution(day) {
+            Some(solution) => vec![solution],
+            None => {
+                eprintln!("Day {day} is not yet implemented");
+                return None;
+            }
+        },
+        None => year.solutions.to_vec(),
+    };
+
+    if let Some(variant) = &select.variant {
+        for solution in &solutions {
+            for part in select.parts() {
+                let variants = solution.variants(part);
+                if variants.len() < 2 {
+                    eprintln!(
+                        "Day {} part {} has no variants",
+                        solution.day(),
+                        part.number()
+                    );
+                    return None;
+                }
+                if !variants.contains(&variant.as_str()) {
+                    eprintln!(
+                        "Day {} part {} has no variant {variant}, choose from: {}",
+                        solution.day(),
+                        part.number(),
+                        variants.join(", ")
+                    );
+                    return None;
+                }
+            }
+        }
+    }
+    Some(solutions)
+}
+
+fn cross_check(
+    year: &Year,
+    select: &Selection,
+    solutions: Vec<&dyn DynSolution>,
+    limits: Limits,
+) -> ExitCode {
+    let parts = select.parts();
+    let mut failed = false;
+    let mut checked = 0;
+    for solution in solutions {
+        // Nothing to compare when every part only has its default implementation
+        if select.all && parts.iter().all(|p| solution.variants(*p).len() < 2) {
+            continue;
+        }
+        print_header(solution);
+        for input in select.inputs() {
+            let results = if limits.is_unlimited() {
+                runner::cross_check(solution, &input, &parts)
+            } else {
+                cross_check_isolated(year.year, solution, &input, &parts, limits)
+            };
+            match results {
+                Ok(results) => {
+                    for (part, runs) in results {
+                        failed |= !report::print_cross_check(&input.label(part), &runs);
+                    }
+                }
+                Err(Outcome::Missing(err)) => {
+                    if select.input_required() {
+                        println!("{input}: ERROR! {err}");
+                        failed = true;
+                    }
+                }
+                Err(outcome) => {
+                    println!("{input}: {outcome}");
+                    failed = true;
+                }
+            }
+        }
+        println!();
+        checked += 1;
+    }
+    if checked == 0 {
+        println!("No day with more than one variant was selected");
+    }
+
+    if failed {
+        ExitCode::FAILURE
+    } else {
+        ExitCode::SUCCESS
+    }
+}
+
+pub fn run(args: &RunArgs, year: &Year, limits: Limits) -> ExitCode {
+    let select = &args.select;
+    let Some(solutions) = select_solutions(year, select) else {
+        return ExitCode::FAILURE;
+    };
+    if args.cross_check {
+        return cross_check(year, select, solutions, limits);
+    }
+    if args.watch {
+        if select.input == Some(InputKind::Stdin) {
+            eprintln!("Can't watch stdin, save the input to a file instead");
+            return ExitCode::FAILURE;
+        }
+        watch(args, year, &solutions, limits);
+    }
+
+    if run_selected(args, year, &solutions, limits) {
+        ExitCode::FAILURE
+    } else {
+        ExitCode::SUCCESS
+    }
+}
+
+// Re-runs every time one of the input files changes, until interrupted
+fn watch(args: &RunArgs, year: &Year, solutions: &[&dyn DynSolution], limits: Limits) -> ! {
+    let paths: Vec<PathBuf> = solutions
+        .iter()
+        .flat_map(|solution| {
+            let day = solution.day();
+            args.select
+                .inputs()
+                .into_iter()
+                .filter_map(move |input| input.file_path(day))
+        })
+        .collect();
+    let names: Vec<String> = paths.iter().map(|p| p.display().to_string()).collect();
+    loop {
+        watch::clear_screen();
+        run_selected(args, year, solutions, limits);
+        println!("Watching {} for changes, Ctrl-C to stop", names.join(", "));
+        watch::wait_for_change(&paths);
+    }
+}
+
+/// Runs the selected days and prints the results. Returns whether anything failed.
+fn run_selected(
+    args: &RunArgs,
+    year: &Year,
+    solutions: &[&dyn DynSolution],
+    limits: Limits,
+) -> bool {
+    let select = &args.select;
+    let answers = match KnownAnswers::load() {
+        Ok(answers) => answers,
+        Err(err) => {
+            eprintln!("{err}");
+            return true;
+        }
+    };
+
+    let tasks: Vec<(&dyn DynSolution, InputKind)> = solutions
+        .iter()
+        .flat_map(|solution| select.inputs().into_iter().map(|input| (*solution, input)))
+        .collect();
+    let now = Instant::now();
+    let runs = run_parallel(&tasks, args.jobs, |(solution, input)| {
+        let mut run = execute(
+            year,
+            *solution,
+            input,
+            &select.parts(),
+            select.variant.as_deref(),
+            limits,
+        );
+        answers.verify(&mut run);
+        run
+    });
+
+    // Per-day output is only printed for a single day in text format
+    if !select.all && args.format == Format::Text {
+        for (i, ((solution, _), run)) in tasks.iter().zip(&runs).enumerate() {
+            if i == 0 || tasks[i - 1].0.day() != solution.day() {
+                print_header(*solution);
+            }
+            print_input_run(run);
+        }
+    }
+
+    match args.format {
+        Format::Text if select.all => report::print_table(&runs, now.elapsed()),
+        Format::Text => println!(),
+        Format::Json => report::print_json(&runs),
+        Format::Csv => report::print_csv(&runs),
+    }
+
+    runs.iter()
+        .flat_map(|run| &run.parts)
+        .any(|p| match p.outcome {
+            Outcome::Solved(_) => matches!(p.verdict, Verdict::Wrong { .. }),
+            Outcome::Missing(_) => select.input_required(),
+            Outcome::Panicked(_) | Outcome::TimedOut(_) | Outcome::OutOfMemory => true,
+        })
+}
+
+pub fn bench(args: &BenchArgs, year: &Year, sets_limits: bool) -> ExitCode {
+    // Timings are taken in this process, where the limits can't be enforced
+    if sets_limits {
+        eprintln!("bench runs in-process, it can't be used with --timeout or --memory-limit");
+        return ExitCode::FAILURE;
+    }
+    let select = &args.select;
+    let Some(solutions) = select_solutions(year, select) else {
+        return ExitCode::FAILURE;
+    };
+    let bench = Bench {
+        warmup: args.warmup,
+        runs: args.runs,
+        variant: select.variant.clone(),
+    };
+
+    let commit = history::current_commit();
+    let date = history::now_utc();
+    let mut entries: Vec<Entry> = vec![];
+    let mut failed = false;
+    let mut skipped = 0;
+    for solution in solutions {
+        println!("Day {}: {}", solution.day(), solution.title());
+        for input in select.inputs() {
+            match bench_input(&bench, solution, &input, &select.parts()) {
+                Ok(steps) => {
+                    let label = input.to_string();
+                    entries.extend(steps.iter().map(|(step, stats)| {
+                        Entry::new(
+                            &commit,
+                            &date,
+                            solution.day(),
+                            &label,
+                            step,
+                            bench.runs,
+                            stats,
+                        )
+                    }));
+                }
+                Err(Outcome::Missing(err)) => {
+                    if select.input_required() {
+                        println!("{input}: ERROR! {err}");
+                        failed = true;
+                    } else {
+                        skipped += 1;
+                    }
+                }
+                Err(_) => failed = true,
+            }
+        }
+        println!();
+    }
+    if skipped > 0 {
+        println!("Skipped {skipped} missing input(s)");
+    }
+    if !args.no_save && !entries.is_empty() {
+        match history::append(&entries) {
+            Ok(()) => println!("Saved {} timing(s) for commit {commit}", entries.len()),
+            Err(err) => {
+                eprintln!("Could not save benchmark history: {err}");
+                failed = true;
+            }
+        }
+    }
+
+    if failed {
+        ExitCode::FAILURE
+    } else {
+        ExitCode::SUCCESS
+    }
+}
+
+pub fn compare(args: &CompareArgs) -> ExitCode {
+    let result = history::load().and_then(|entries| {
+        history::compare(
+            &entries,
+            &args.baseline,
+            args.against.as_deref(),
+            args.threshold,
+        )
+    });
+    match result {
+        Ok(false) => ExitCode::SUCCESS,
+        Ok(true) => ExitCode::FAILURE,
+        Err(err) => {
+            eprintln!("{err}");
+            ExitCode::FAILURE
+        }
+    }
+}
+
+pub fn new_day(args: &NewDayArgs) -> ExitCode {
+    match scaffold::new_day(args.day) {
+        Ok(paths) => {
+            for path in paths {
+                println!("Wrote {path}");
+            }
+            println!(
+                "Paste the example into the input file and fill in the title and expected answers."
+            );
+            ExitCode::SUCCESS
+        }
+        Err(err) => {
+            eprintln!("{err}");
+            ExitCode::FAILURE
+        }
+    }
+}
+
+pub fn extract(args: &ExtractArgs) -> ExitCode {
+    match extract::extract(args.day, &args.page, args.example, args.force) {
+        Ok(written) => {
+            for line in written {
+                println!("{line}");
+            }
+            ExitCode::SUCCESS
+        }
+        Err(err) => {
+            eprintln!("{err}");
+            ExitCode::FAILURE
+        }
+    }
+}
+
+pub fn fetch(args: &FetchArgs, year: &Year) -> ExitCode {
+    match Config::load().and_then(|config| fetch::fetch_input(&config, year.year, args.day)) {
+        Ok(Fetched::Downloaded(path)) => {
+            println!("Downloaded day {} to {}", args.day, path.display());
+            ExitCode::SUCCESS
+        }
+        Ok(Fetched::Cached(path)) => {
+            println!("{} already exists, not downloading again", path.display());
+            ExitCode::SUCCESS
+        }
+        Err(err) => {
+            eprintln!("{err}");
+            ExitCode::FAILURE
+        }
+    }
+}
+
+// Solves the actual input of the part to submit, unless an answer was given
+fn answer_to_submit(
+    args: &SubmitArgs,
+    year: &Year,
+    part: Part,
+    limits: Limits,
+) -> Result<String, String> {
+    if let Some(answer) = &args.answer {
+        return Ok(answer.trim().to_string());
+    }
+    let solution = year
+        .find_solution(args.day)
+        .ok_or(format!("Day {} is not yet implemented", args.day))?;
+    let run = execute(year, solution, &InputKind::Actual, &[part], None, limits);
+    match run.parts.into_iter().next().map(|p| p.outcome) {
+        Some(Outcome::Solved(answer)) => Ok(answer.to_string()),
+        Some(Outcome::Missing(err)) => Err(format!("{err}")),
+        Some(outcome) => Err(format!("{}: {outcome}", InputKind::Actual.label(part))),
+        None => Err(format!("{}: no answer", InputKind::Actual.label(part))),
+    }
+}
+
+// Submits the answer and remembers it when it was judged
+fn submit_answer(
+    args: &SubmitArgs,
+    year: &Year,
+    limits: Limits,
+) -> Result<submit::Response, String> {
+    let part = if args.part == 1 { Part::One } else { Part::Two };
+    let config = Config::load()?;
+    let mut log = GuessLog::load()?;
+    let answer = answer_to_submit(args, year, part, limits)?;
+    log.check(year.year, args.day, part, &answer)
+        .map_err(|reason| format!("Not submitting {answer}: {reason}"))?;
+
+    println!(
+        "Submitting {answer} for day {} part {}",
+        args.day, args.part
+    );
+    let response = submit::post_answer(&config, year.year, args.day, part, &answer)?;
+    println!("{response}");
+    if response.is_verdict() {
+        log.append(Guess {
+            year: year.year,
+            day: args.day,
+            part: args.part,
+            answer,
+            response: response.clone(),
+            date: history::now_utc(),
+        })
+        .map_err(|e| format!("Could not save the guess: {e}"))?;
+    }
+    Ok(response)
+}
+
+pub fn submit(args: &SubmitArgs, year: &Year, limits: Limits) -> ExitCode {
+    match submit_answer(args, year, limits) {
+        Ok(submit::Response::Correct) => ExitCode::SUCCESS,
+        Ok(_) => ExitCode::FAILURE,
+        Err(err) => {
+            eprintln!("{err}");
+            ExitCode::FAILURE
+        }
+    }
+}
+
+pub fn serve(args: &ServeArgs, year: &Year, limits: Limits) -> ExitCode {
+    match serve::serve(year, &args.host, args.port, args.jobs, limits) {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(err) => {
+            eprintln!("{err}");
+            ExitCode::FAILURE
+        }
+    }
+}
+
+pub fn worker(args: &WorkerArgs, year: &Year, memory_limit: Option<u64>) -> ExitCode {
+    if let Some(mb) = memory_limit {
+        if let Err(err) = set_memory_limit(mb) {
+            eprintln!("Could not set memory limit: {err}");
+            return ExitCode::FAILURE;
+        }
+    }
+    let Some(solution) = year.find_solution(args.day) else {
+        eprintln!("Day {} is not yet implemented", args.day);
+        return ExitCode::FAILURE;
+    };
+    let part = if args.part == 1 { Part::One } else { Part::Two };
+    let variant = args.variant.as_deref();
+    let run = if args.text {
+        let mut text = String::new();
+        if let Err(err) = stdin().read_to_string(&mut text) {
+            eprintln!("Could not read the input from stdin: {err}");
+            return ExitCode::FAILURE;
+        }
+        run_text(solution, &args.input, &text, &[part], variant)
+    } else {
+        run_input(solution, &args.input, &[part], variant)
+    };
+    match serde_json::to_string(&run) {
+        Ok(json) => {
+            println!("{json}");
+            ExitCode::SUCCESS
+        }
+        Err(err) => {
+            eprintln!("{err}");
+            ExitCode::FAILURE
+        }
+    }
+}
@@ -0,0 +1,77 @@
+use std::env;
+use std::fs::read_to_string;
+use std::io::ErrorKind;
+
+use serde::Deserialize;
+
+pub const CONFIG_DIR: &str = ".aoc";
+const CONFIG: &str = ".aoc/config.toml";
+const SESSION_VAR: &str = "AOC_SESSION";
+
+/// Settings for talking to the Advent of Code website, read from
+/// `.aoc/config.toml`:
+///
+/// ```toml
+/// session = "53616c7465645f5f..."
+/// ```
+///
+/// The session token is the value of the `session` cookie of a logged in
+/// browser, it can also be set with `AOC_SESSION`.
+#[derive(Deserialize, Clone, Debug)]
+#[serde(default, deny_unknown_fields)]
+pub struct Config {
+    pub base_url: String,
+    pub session: Option<String>,
+}
+
+impl Default for Config {
+    fn default() -> Self {
+        Config {
+            base_url: "https://adventofcode.com".to_string(),
+            session: None,
+        }
+    }
+}
+
+impl Config {
+    /// Reads the config file, a missing file means the defaults
+    pub fn load() -> Result<Config, String> {
+        let mut config: Config = match read_to_string(CONFIG) {
+            Ok(text) => {
+                toml::from_str(&text).map_err(|e| format!("could not read {CONFIG}: {e}"))?
+            }
+            Err(e) if e.kind() == ErrorKind::NotFound => Config::default(),
+            Err(e) => return Err(format!("could not read {CONFIG}: {e}")),
+        };
+        if let Ok(session) = env::var(SESSION_VAR) {
+            config.session = Some(session);
+        }
+        Ok(config)
+    }
+
+    pub fn session(&self) -> Result<&str, String> {
+        self.session
+            .as_deref()
+            .map(str::trim)
+            .filter(|s| !s.is_empty())
+            .ok_or(format!(
+                "No session token, set `session` in {CONFIG} or {SESSION_VAR}"
+            ))
+    }
+
+    /// URL of a day's puzzle page, the input and answer URLs are under it
+    pub fn day_url(&self, year: u32, day: u32) -> String {
+        format!("{}/{year}/day/{day}", self.base_url.trim_end_matches('/'))
+    }
+}
+
+/// Blocking HTTP client that sends the session cookie and identifies the
+/// repo, as the site asks automated tools to
+pub fn agent() -> ureq::Agent {
+    ureq::Agent::config_builder()
+        .http_status_as_error(false)
+        .user_agent("github.com/scramblerdoodle/advent_of_code")
+        .timeout_global(Some(std::time::Duration::from_secs(30)))
+        .build()
+        .into()
+}
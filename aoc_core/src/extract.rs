@@ -0,0 +1,225 @@
+use std::fs::{read_to_string, write};
+use std::io::ErrorKind;
+use std::path::Path;
+
+use regex::Regex;
+
+use crate::utils::{test_file_path, EXAMPLE};
+
+/// The examples and example answers of a saved puzzle page
+#[derive(PartialEq, Debug)]
+pub struct Puzzle {
+    /// Every `<pre><code>` block, in page order
+    pub examples: Vec<String>,
+    /// The answer to the example of each part that is on the page
+    pub answers: Vec<String>,
+}
+
+fn decode_entities(text: &str) -> String {
+    text.replace("&lt;", "<")
+        .replace("&gt;", ">")
+        .replace("&quot;", "\"")
+        .replace("&#39;", "'")
+        .replace("&amp;", "&")
+}
+
+// Drops the markup inside a block, e.g. the <em> highlighting a path in a grid
+fn text(html: &str) -> String {
+    decode_entities(&Regex::new(r"<[^>]*>").unwrap().replace_all(html, ""))
+}
+
+/// Pulls the examples and answers out of a puzzle page saved from the browser.
+/// Each part is its own `<article class="day-desc">`, and its example answer
+/// is the last emphasized code in it, e.g. `<code><em>161</em></code>`.
+pub fn parse_page(html: &str) -> Puzzle {
+    let examples = Regex::new(r"(?s)<pre><code>(.*?)</code></pre>")
+        .unwrap()
+        .captures_iter(html)
+        .map(|c| text(&c[1]))
+        .collect();
+
+    let emphasized =
+        Regex::new(r"(?s)<code><em>(.*?)</em></code>|<em><code>(.*?)</code></em>").unwrap();
+    let answers = Regex::new(r#"(?s)<article class="day-desc">(.*?)</article>"#)
+        .unwrap()
+        .captures_iter(html)
+        .filter_map(|article| {
+            emphasized
+                .captures_iter(&article[1])
+                .last()
+                .and_then(|c| c.get(1).or(c.get(2)))
+                .map(|m| text(m.as_str()).trim().to_string())
+        })
+        .collect();
+
+    Puzzle { examples, answers }
+}
+
+fn toml_value(answer: &str) -> String {
+    match answer.parse::<i64>() {
+        Ok(n) => n.to_string(),
+        Err(_) => toml::Value::String(answer.to_string()).to_string(),
+    }
+}
+
+/// Writes the answers of `day` into the text of an `answers.toml`, keeping
+/// everything else as it is. Changing an answer that is already there needs
+/// `force`.
+fn set_answers(toml: &str, day: u32, answers: &[String], force: bool) -> Result<String, String> {
+    let header = format!("[day{day:02}]");
+    let mut lines: Vec<String> = toml.lines().map(String::from).collect();
+
+    let start = match lines.iter().position(|l| l.trim() == header) {
+        Some(i) => i,
+        // Keep the days in order
+        None => match lines
+            .iter()
+            .position(|l| l.starts_with("[day") && l.as_str() > header.as_str())
+        {
+            Some(i) => {
+                lines.splice(i..i, [header, String::new()]);
+                i
+            }
+            None => {
+                if lines.last().is_some_and(|l| !l.trim().is_empty()) {
+                    lines.push(String::new());
+                }
+                lines.push(header);
+                lines.len() - 1
+            }
+        },
+    };
+
+    for (n, answer) in answers.iter().enumerate() {
+        let key = format!("part{}", n + 1);
+        let line = format!("{key} = {}", toml_value(answer));
+        let end = lines[start + 1..]
+            .iter()
+            .position(|l| l.starts_with('['))
+            .map_or(lines.len(), |i| start + 1 + i);
+        let existing = lines[start + 1..end].iter().position(|l| {
+            l.split_once('=')
+                .is_some_and(|(name, _)| name.trim() == key)
+        });
+        match existing {
+            Some(i) if lines[start + 1 + i] == line => (),
+            Some(i) if force => lines[start + 1 + i] = line,
+            Some(i) => {
+                return Err(format!(
+                    "Day {day} already has `{}`, use --force to replace it with `{line}`",
+                    lines[start + 1 + i]
+                ))
+            }
+            None => {
+                // After the section's last key, before the blank lines between sections
+                let i = lines[start + 1..end]
+                    .iter()
+                    .rposition(|l| !l.trim().is_empty())
+                    .map_or(start + 1, |i| start + 2 + i);
+                lines.insert(i, line);
+            }
+        }
+    }
+    Ok(lines.join("\n") + "\n")
+}
+
+/// Saves example `example` (counting from 1) of a saved puzzle page as the
+/// day's example input, and its answers in `answers.toml`. Returns what was
+/// written.
+pub fn extract(day: u32, page: &Path, example: usize, force: bool) -> Result<Vec<String>, String> {
+    let html =
+        read_to_string(page).map_err(|e| format!("Could not read {}: {e}", page.display()))?;
+    let puzzle = parse_page(&html);
+    if puzzle.examples.is_empty() {
+        return Err(format!("No <pre><code> blocks in {}", page.display()));
+    }
+    let Some(input) = puzzle.examples.get(example - 1) else {
+        return Err(format!(
+            "There are only {} example block(s) in {}",
+            puzzle.examples.len(),
+            page.display()
+        ));
+    };
+
+    let input_path = test_file_path(EXAMPLE, &format!("{day:02}"));
+    let answers_path = format!("./{EXAMPLE}/answers.toml");
+    let answers = match read_to_string(&answers_path) {
+        Ok(text) => text,
+        Err(e) if e.kind() == ErrorKind::NotFound => String::new(),
+        Err(e) => return Err(format!("Could not read {answers_path}: {e}")),
+    };
+    let answers = set_answers(&answers, day, &puzzle.answers, force)?;
+    match read_to_string(&input_path) {
+        Ok(old) if !force && !old.trim().is_empty() && old != *input => {
+            return Err(format!(
+                "{input_path} already has a different example, use --force to replace it"
+            ))
+        }
+        _ => (),
+    }
+
+    write(&input_path, input).map_err(|e| format!("Could not write {input_path}: {e}"))?;
+    write(&answers_path, answers).map_err(|e| format!("Could not write {answers_path}: {e}"))?;
+
+    let mut written = vec![format!(
+        "Wrote example {example} of {} ({} lines) to {input_path}",
+        puzzle.examples.len(),
+        input.lines().count()
+    )];
+    for (n, answer) in puzzle.answers.iter().enumerate() {
+        written.push(format!("Part {} answer {answer} in {answers_path}", n + 1));
+    }
+    Ok(written)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const PAGE: &str = r#"<main>
+<article class="day-desc"><h2>--- Day 3: Mull It Over ---</h2>
+<p>For example, consider the following section of corrupted memory:</p>
+<pre><code>x<em>mul(2,4)</em>%&amp;mul[3,7]!@^do_not_<em>mul(5,5)</em>+mul(32,64]then(<em>mul(11,8)</em><em>mul(8,5)</em>)
+</code></pre>
+<p>Only the four highlighted sections are real <code>mul</code> instructions. Adding up the result of each instruction produces <code><em>161</em></code> (<code>2*4 + 5*5 + 11*8 + 8*5</code>).</p>
+</article>
+<p>Your puzzle answer was <code>170807108</code>.</p>
+<article class="day-desc"><h2 id="part2">--- Part Two ---</h2>
+<pre><code>xmul(2,4)&amp;mul[3,7]!^don't()_mul(5,5)+mul(32,64](mul(11,8)undo()?mul(8,5))
+</code></pre>
+<p>This time, the sum of the results is <em><code>48</code></em>.</p>
+</article>
+</main>"#;
+
+    #[test]
+    fn test_parse_page() {
+        let puzzle = parse_page(PAGE);
+        assert_eq!(puzzle.examples.len(), 2);
+        assert_eq!(
+            puzzle.examples[0],
+            "xmul(2,4)%&mul[3,7]!@^do_not_mul(5,5)+mul(32,64]then(mul(11,8)mul(8,5))\n"
+        );
+        assert!(puzzle.examples[1].contains("don't()"));
+        assert_eq!(puzzle.answers, vec!["161", "48"]);
+    }
+
+    #[test]
+    fn test_set_answers() {
+        let toml = "# Example answers\n\n[day01]\npart1 = 11\npart2 = 31\n\n[day05]\npart1 = 143\n";
+        let answers = vec!["161".to_string(), "abc".to_string()];
+
+        let updated = set_answers(toml, 3, &answers, false).unwrap();
+        assert!(updated.contains("part2 = 31\n\n[day03]\npart1 = 161\npart2 = \"abc\"\n\n[day05]"));
+
+        let updated = set_answers(toml, 5, &answers, true).unwrap();
+        assert!(updated.ends_with("[day05]\npart1 = 161\npart2 = \"abc\"\n"));
+        assert!(set_answers(toml, 5, &answers, false).is_err());
+
+        let updated = set_answers(toml, 7, &answers[..1], false).unwrap();
+        assert!(updated.ends_with("part1 = 143\n\n[day07]\npart1 = 161\n"));
+        assert_eq!(
+            set_answers("", 1, &answers[..1], false).unwrap(),
+            "[day01]\npart1 = 161\n"
+        );
+    }
+}
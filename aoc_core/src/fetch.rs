@@ -0,0 +1,109 @@
+use std::fs::{create_dir_all, rename, write};
+use std::path::{Path, PathBuf};
+
+use crate::config::{agent, Config};
+use crate::utils::{test_file_path, ACTUAL};
+
+pub enum Fetched {
+    Downloaded(PathBuf),
+    /// The input was downloaded before, the site asks not to request it again
+    Cached(PathBuf),
+}
+
+/// Downloads a day's input to where `get_test_file(ACTUAL, ..)` looks for it,
+/// unless it is already there
+pub fn fetch_input(config: &Config, year: u32, day: u32) -> Result<Fetched, String> {
+    let path = test_file_path(ACTUAL, &format!("{day:02}"));
+    download(config, year, day, Path::new(&path))
+}
+
+fn download(config: &Config, year: u32, day: u32, path: &Path) -> Result<Fetched, String> {
+    if path.exists() {
+        return Ok(Fetched::Cached(path.to_path_buf()));
+    }
+    let session = config.session()?;
+    let url = format!("{}/input", config.day_url(year, day));
+    let mut response = agent()
+        .get(&url)
+        .header("Cookie", &format!("session={session}"))
+        .call()
+        .map_err(|e| format!("Could not download {url}: {e}"))?;
+    let body = response
+        .body_mut()
+        .read_to_string()
+        .map_err(|e| format!("Could not read {url}: {e}"))?;
+    if !response.status().is_success() {
+        // Locked days and expired sessions come with a one-line explanation
+        return Err(format!(
+            "{url} returned {}: {}",
+            response.status(),
+            body.trim()
+        ));
+    }
+
+    // Written under another name first so a failed write is never mistaken for a cached input
+    if let Some(dir) = path.parent() {
+        create_dir_all(dir).map_err(|e| format!("Could not create {}: {e}", dir.display()))?;
+    }
+    let partial = path.with_extension("part");
+    write(&partial, body).map_err(|e| format!("Could not write {}: {e}", partial.display()))?;
+    rename(&partial, path).map_err(|e| format!("Could not write {}: {e}", path.display()))?;
+    Ok(Fetched::Downloaded(path.to_path_buf()))
+}
+
+#[cfg(test)]
+mod tests {
+    use std::env::temp_dir;
+    use std::fs::{read_to_string, remove_dir_all};
+    use std::process;
+
+    use super::*;
+    use crate::mock_server::MockServer;
+
+    fn config(url: &str) -> Config {
+        Config {
+            base_url: url.to_string(),
+            session: Some("secret".to_string()),
+        }
+    }
+
+    #[test]
+    fn test_download() {
+        let dir = temp_dir().join(format!("aoc-fetch-{}", process::id()));
+        let path = dir.join("day05.txt");
+
+        let server = MockServer::start(vec![(200, "1\n2\n3\n")]);
+        let url = server.url.clone();
+        assert!(matches!(
+            download(&config(&url), 2024, 5, &path),
+            Ok(Fetched::Downloaded(_))
+        ));
+        let requests = server.requests();
+        assert!(requests[0].starts_with("GET /2024/day/5/input HTTP/1.1\r\n"));
+        assert!(requests[0].contains("session=secret"));
+        assert_eq!(read_to_string(&path).unwrap(), "1\n2\n3\n");
+
+        // The server stopped after one response, so this only works from the cache
+        assert!(matches!(
+            download(&config(&url), 2024, 5, &path),
+            Ok(Fetched::Cached(_))
+        ));
+        remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn test_download_error() {
+        let dir = temp_dir().join(format!("aoc-fetch-error-{}", process::id()));
+        let path = dir.join("day25.txt");
+        let server = MockServer::start(vec![(
+            404,
+            "Please don't repeatedly request this endpoint before it unlocks!",
+        )]);
+        let err = download(&config(&server.url), 2024, 25, &path)
+            .err()
+            .unwrap();
+        assert!(err.contains("404"));
+        assert!(err.contains("before it unlocks"));
+        assert!(!path.exists());
+    }
+}
@@ -0,0 +1,258 @@
+use std::collections::BTreeMap;
+use std::fs::{create_dir_all, read_to_string, OpenOptions};
+use std::io::{ErrorKind, Write};
+use std::process::Command;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+use serde::{Deserialize, Serialize};
+
+use crate::bench::Stats;
+use crate::report::print_columns;
+
+const HISTORY_DIR: &str = ".bench";
+const HISTORY: &str = ".bench/history.jsonl";
+
+/// One benchmarked step (parse or a part) of a day, as saved in the history
+#[derive(Serialize, Deserialize)]
+pub struct Entry {
+    pub commit: String,
+    pub date: String,
+    pub day: u32,
+    pub input: String,
+    pub step: String,
+    pub runs: u32,
+    pub min_ns: u64,
+    pub median_ns: u64,
+    pub mean_ns: u64,
+    pub p95_ns: u64,
+}
+
+impl Entry {
+    pub fn new(
+        commit: &str,
+        date: &str,
+        day: u32,
+        input: &str,
+        step: &str,
+        runs: u32,
+        stats: &Stats,
+    ) -> Entry {
+        let ns = |d: Duration| d.as_nanos() as u64;
+        Entry {
+            commit: commit.to_string(),
+            date: date.to_string(),
+            day,
+            input: input.to_string(),
+            step: step.to_string(),
+            runs,
+            min_ns: ns(stats.min),
+            median_ns: ns(stats.median),
+            mean_ns: ns(stats.mean),
+            p95_ns: ns(stats.p95),
+        }
+    }
+}
+
+fn git(args: &[&str]) -> Option<String> {
+    let output = Command::new("git").args(args).output().ok()?;
+    output
+        .status
+        .success()
+        .then(|| String::from_utf8_lossy(&output.stdout).trim().to_string())
+}
+
+/// Short hash of the checked out commit, with `-dirty` when there are
+/// uncommitted changes since the timings may not match the commit
+pub fn current_commit() -> String {
+    match git(&["rev-parse", "--short", "HEAD"]) {
+        Some(hash) if git(&["status", "--porcelain"]).is_some_and(|s| !s.is_empty()) => {
+            format!("{hash}-dirty")
+        }
+        Some(hash) => hash,
+        None => "unknown".to_string(),
+    }
+}
+
+/// Current UTC time as `YYYY-MM-DDTHH:MM:SSZ`
+pub fn now_utc() -> String {
+    let secs = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|d| d.as_secs())
+        .unwrap_or(0);
+    let (days, rem) = ((secs / 86400) as i64, secs % 86400);
+
+    // Days since 1970-01-01 to a civil date, from Howard Hinnant's `civil_from_days`
+    let z = days + 719468;
+    let era = z.div_euclid(146097);
+    let doe = z.rem_euclid(146097);
+    let yoe = (doe - doe / 1460 + doe / 36524 - doe / 146096) / 365;
+    let doy = doe - (365 * yoe + yoe / 4 - yoe / 100);
+    let mp = (5 * doy + 2) / 153;
+    let day = doy - (153 * mp + 2) / 5 + 1;
+    let month = if mp < 10 { mp + 3 } else { mp - 9 };
+    let year = yoe + era * 400 + i64::from(month <= 2);
+
+    format!(
+        "{year:04}-{month:02}-{day:02}T{:02}:{:02}:{:02}Z",
+        rem / 3600,
+        rem / 60 % 60,
+        rem % 60
+    )
+}
+
+pub fn append(entries: &[Entry]) -> Result<(), String> {
+    create_dir_all(HISTORY_DIR).map_err(|e| e.to_string())?;
+    let mut file = OpenOptions::new()
+        .create(true)
+        .append(true)
+        .open(HISTORY)
+        .map_err(|e| e.to_string())?;
+    for entry in entries {
+        let line = serde_json::to_string(entry).map_err(|e| e.to_string())?;
+        writeln!(file, "{line}").map_err(|e| e.to_string())?;
+    }
+    Ok(())
+}
+
+pub fn load() -> Result<Vec<Entry>, String> {
+    let text = match read_to_string(HISTORY) {
+        Ok(text) => text,
+        Err(e) if e.kind() == ErrorKind::NotFound => return Ok(vec![]),
+        Err(e) => return Err(format!("could not read {HISTORY}: {e}")),
+    };
+    text.lines()
+        .enumerate()
+        .filter(|(_, line)| !line.trim().is_empty())
+        .map(|(i, line)| {
+            serde_json::from_str(line).map_err(|e| format!("{HISTORY}:{}: {e}", i + 1))
+        })
+        .collect()
+}
+
+// Finds the one commit in the history that `wanted` names, exactly or by prefix
+fn resolve_commit<'a>(entries: &'a [Entry], wanted: &str) -> Result<&'a str, String> {
+    if let Some(entry) = entries.iter().find(|e| e.commit == wanted) {
+        return Ok(&entry.commit);
+    }
+    let mut matches: Vec<&str> = entries
+        .iter()
+        .map(|e| e.commit.as_str())
+        .filter(|c| c.starts_with(wanted))
+        .collect();
+    matches.sort();
+    matches.dedup();
+    match matches[..] {
+        [] => Err(format!("No benchmarks saved for commit {wanted}")),
+        [commit] => Ok(commit),
+        _ => Err(format!(
+            "Commit {wanted} is ambiguous: {}",
+            matches.join(", ")
+        )),
+    }
+}
+
+type Key<'a> = (u32, &'a str, &'a str);
+
+// Latest entry of `commit` for each day, input and step
+fn latest<'a>(entries: &'a [Entry], commit: &str) -> BTreeMap<Key<'a>, &'a Entry> {
+    entries
+        .iter()
+        .filter(|e| e.commit == commit)
+        .map(|e| ((e.day, e.input.as_str(), e.step.as_str()), e))
+        .collect()
+}
+
+/// Prints the median time of each step of `current` against `baseline`, flagging
+/// steps that got slower by more than `threshold` percent. `current` defaults to
+/// the most recently saved commit. Returns whether there were any regressions.
+pub fn compare(
+    entries: &[Entry],
+    baseline: &str,
+    current: Option<&str>,
+    threshold: f64,
+) -> Result<bool, String> {
+    let baseline = resolve_commit(entries, baseline)?;
+    let current = match current {
+        Some(current) => resolve_commit(entries, current)?,
+        None => &entries.last().expect("Baseline was found").commit,
+    };
+    println!("Comparing {current} against {baseline}, threshold {threshold}%");
+
+    let before = latest(entries, baseline);
+    let after = latest(entries, current);
+    let mut rows: Vec<[String; 7]> = vec![];
+    let mut regressions = 0;
+    for (key, entry) in &after {
+        let Some(old) = before.get(key) else {
+            continue;
+        };
+        let change =
+            (entry.median_ns as f64 - old.median_ns as f64) / old.median_ns.max(1) as f64 * 100.0;
+        let note = if change > threshold {
+            regressions += 1;
+            "REGRESSION"
+        } else if change < -threshold {
+            "faster"
+        } else {
+            ""
+        };
+        let (day, input, step) = key;
+        rows.push([
+            day.to_string(),
+            input.to_string(),
+            step.to_string(),
+            format!("{:.2?}", Duration::from_nanos(old.median_ns)),
+            format!("{:.2?}", Duration::from_nanos(entry.median_ns)),
+            format!("{change:+.1}%"),
+            note.to_string(),
+        ]);
+    }
+
+    if rows.is_empty() {
+        return Err(format!(
+            "{baseline} and {current} have no benchmarks in common"
+        ));
+    }
+    print_columns(
+        "",
+        ["Day", "Input", "Step", "Baseline", "Current", "Change", ""],
+        &rows,
+        &[0, 3, 4, 5],
+    );
+    println!();
+    println!("{regressions} regression(s) over {threshold}%");
+    Ok(regressions > 0)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn entry(commit: &str, step: &str, median_ns: u64) -> Entry {
+        Entry {
+            commit: commit.to_string(),
+            date: "2024-12-01T00:00:00Z".to_string(),
+            day: 1,
+            input: "Actual".to_string(),
+            step: step.to_string(),
+            runs: 20,
+            min_ns: median_ns,
+            median_ns,
+            mean_ns: median_ns,
+            p95_ns: median_ns,
+        }
+    }
+
+    #[test]
+    fn test_compare() {
+        let entries = vec![
+            entry("abc1234", "Part 1", 100),
+            entry("abc1234", "Part 2", 100),
+            entry("def5678", "Part 1", 105),
+            entry("def5678", "Part 2", 150),
+        ];
+        assert_eq!(compare(&entries, "abc", None, 10.0), Ok(true));
+        assert_eq!(compare(&entries, "abc", Some("def"), 60.0), Ok(false));
+        assert!(compare(&entries, "123", None, 10.0).is_err());
+    }
+}
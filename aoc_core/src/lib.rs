@@ -0,0 +1,46 @@
+//! What every year of Advent of Code solutions shares: the `Solution` trait,
+//! the grid and direction helpers in `utils`, and the runner behind the `aoc`
+//! binary. A year crate implements `Solution` for each day and registers them
+//! as a [`Year`](solution::Year).
+//!
+//! Everything but `solution` and `utils` is behind the default `runner`
+//! feature, so a year's solutions can be built without it, e.g. for wasm.
+
+#[cfg(feature = "runner")]
+pub mod alloc;
+#[cfg(feature = "runner")]
+mod answers;
+#[cfg(feature = "runner")]
+mod bench;
+#[cfg(feature = "runner")]
+pub mod cli;
+#[cfg(feature = "runner")]
+pub mod commands;
+#[cfg(feature = "runner")]
+mod config;
+#[cfg(feature = "runner")]
+mod extract;
+#[cfg(feature = "runner")]
+mod fetch;
+#[cfg(feature = "runner")]
+mod history;
+#[cfg(feature = "runner")]
+pub mod logging;
+#[cfg(all(test, feature = "runner"))]
+mod mock_server;
+#[cfg(feature = "runner")]
+mod report;
+#[cfg(feature = "runner")]
+mod runner;
+#[cfg(feature = "runner")]
+mod scaffold;
+#[cfg(feature = "runner")]
+mod serve;
+pub mod solution;
+#[cfg(feature = "runner")]
+mod submit;
+pub mod utils;
+#[cfg(feature = "runner")]
+mod watch;
+#[cfg(feature = "runner")]
+mod worker;
@@ -0,0 +1,95 @@
+use std::env;
+use std::io::{stderr, IsTerminal};
+use std::sync::OnceLock;
+
+use tracing_subscriber::EnvFilter;
+
+/// What to log, as given on the command line
+struct Options {
+    verbose: u8,
+    days: Vec<u32>,
+}
+
+static OPTIONS: OnceLock<Options> = OnceLock::new();
+
+/// Filter directives for the events of the solutions in `target`, a year's
+/// crate: `-v` enables debug events, `-vv` trace events, and `days` keeps only
+/// those days' events. Warnings are always shown.
+fn directives(target: &str, verbose: u8, days: &[u32]) -> String {
+    let level = match verbose {
+        0 if days.is_empty() => return "warn".to_string(),
+        0 | 1 => "debug",
+        _ => "trace",
+    };
+    if days.is_empty() {
+        return format!("warn,{target}::day={level}");
+    }
+    // Targets match by prefix, so a day's other versions, e.g. `day15_v2`, come along
+    let days: Vec<String> = days
+        .iter()
+        .map(|day| format!("{target}::day{day:02}={level}"))
+        .collect();
+    format!("warn,{}", days.join(","))
+}
+
+/// Sends the events of the solutions in `target` to stderr. `RUST_LOG`
+/// replaces the filter built from `verbose` and `days` when it is set.
+pub fn init(target: &str, verbose: u8, days: &[u32]) {
+    let filter = match env::var(EnvFilter::DEFAULT_ENV) {
+        Ok(_) => EnvFilter::from_default_env(),
+        Err(_) => EnvFilter::new(directives(target, verbose, days)),
+    };
+    tracing_subscriber::fmt()
+        .with_env_filter(filter)
+        .with_writer(stderr)
+        .with_ansi(stderr().is_terminal())
+        .without_time()
+        .init();
+    let _ = OPTIONS.set(Options {
+        verbose,
+        days: days.to_vec(),
+    });
+}
+
+/// Whether any events were asked for, so a worker's stderr is worth showing
+pub fn is_enabled() -> bool {
+    env::var(EnvFilter::DEFAULT_ENV).is_ok()
+        || OPTIONS
+            .get()
+            .is_some_and(|o| o.verbose > 0 || !o.days.is_empty())
+}
+
+/// The logging flags to pass on to a worker process
+pub fn worker_args() -> Vec<String> {
+    let Some(options) = OPTIONS.get() else {
+        return vec![];
+    };
+    let mut args = vec!["-v".to_string(); options.verbose.into()];
+    for day in &options.days {
+        args.extend(["--log-day".to_string(), day.to_string()]);
+    }
+    args
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_directives() {
+        let target = "advent_of_code_24";
+        assert_eq!(directives(target, 0, &[]), "warn");
+        assert_eq!(
+            directives(target, 1, &[]),
+            "warn,advent_of_code_24::day=debug"
+        );
+        assert_eq!(
+            directives(target, 2, &[5, 12]),
+            "warn,advent_of_code_24::day05=trace,advent_of_code_24::day12=trace"
+        );
+        assert_eq!(
+            directives(target, 0, &[9]),
+            "warn,advent_of_code_24::day09=debug"
+        );
+    }
+}
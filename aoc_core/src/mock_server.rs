@@ -0,0 +1,66 @@
+use std::io::{BufRead, BufReader, Write};
+use std::net::TcpListener;
+use std::thread::{self, JoinHandle};
+
+/// Stand-in for the Advent of Code website in tests. Answers one request per
+/// response on localhost, in order, then stops.
+pub struct MockServer {
+    pub url: String,
+    handle: JoinHandle<Vec<String>>,
+}
+
+impl MockServer {
+    pub fn start(responses: Vec<(u16, &str)>) -> MockServer {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let url = format!("http://{}", listener.local_addr().unwrap());
+        let responses: Vec<(u16, String)> = responses
+            .into_iter()
+            .map(|(status, body)| (status, body.to_string()))
+            .collect();
+        let handle = thread::spawn(move || {
+            responses
+                .into_iter()
+                .map(|(status, body)| {
+                    let (stream, _) = listener.accept().unwrap();
+                    let mut reader = BufReader::new(&stream);
+                    let request = read_request(&mut reader);
+                    write!(
+                        &stream,
+                        "HTTP/1.1 {status} Mock\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{body}",
+                        body.len()
+                    )
+                    .unwrap();
+                    request
+                })
+                .collect()
+        });
+        MockServer { url, handle }
+    }
+
+    /// Waits for every response to be sent and returns the raw requests
+    pub fn requests(self) -> Vec<String> {
+        self.handle.join().unwrap()
+    }
+}
+
+fn read_request(reader: &mut impl BufRead) -> String {
+    let mut request = String::new();
+    let mut length = 0;
+    loop {
+        let mut line = String::new();
+        reader.read_line(&mut line).unwrap();
+        if let Some((name, value)) = line.split_once(':') {
+            if name.eq_ignore_ascii_case("content-length") {
+                length = value.trim().parse().unwrap();
+            }
+        }
+        request.push_str(&line);
+        if line == "\r\n" || line.is_empty() {
+            break;
+        }
+    }
+    let mut body = vec![0; length];
+    reader.read_exact(&mut body).unwrap();
+    request.push_str(&String::from_utf8_lossy(&body));
+    request
+}
@@ -0,0 +1,245 @@
+use std::time::Duration;
+
+use serde::Serialize;
+
+use crate::alloc::Bytes;
+use crate::answers::Verdict;
+use crate::runner::{InputRun, Outcome, VariantRun};
+
+/// Prints `rows` under `headers` with every column padded to its widest cell.
+/// Columns listed in `right_aligned` are aligned to the right.
+pub fn print_columns<const N: usize>(
+    indent: &str,
+    headers: [&str; N],
+    rows: &[[String; N]],
+    right_aligned: &[usize],
+) {
+    let mut widths = headers.map(|h| h.chars().count());
+    for row in rows {
+        for (width, cell) in widths.iter_mut().zip(row) {
+            *width = (*width).max(cell.chars().count());
+        }
+    }
+
+    let print_row = |cells: [&str; N]| {
+        let line = cells
+            .iter()
+            .zip(widths)
+            .enumerate()
+            .map(|(i, (cell, width))| {
+                if right_aligned.contains(&i) {
+                    format!("{cell:>width$}")
+                } else {
+                    format!("{cell:<width$}")
+                }
+            })
+            .collect::<Vec<String>>()
+            .join("  ");
+        println!("{indent}{}", line.trim_end());
+    };
+
+    print_row(headers);
+    for row in rows {
+        print_row(row.each_ref().map(|c| c.as_str()));
+    }
+}
+
+const HEADERS: [&str; 8] = [
+    "Day",
+    "Part",
+    "Input",
+    "Answer",
+    "Time",
+    "Peak heap",
+    "Allocs",
+    "Status",
+];
+
+/// Prints every solved part of `runs` as one aligned table, followed by the
+/// total wall time. Inputs whose file is missing are left out of the table.
+pub fn print_table(runs: &[InputRun], total: Duration) {
+    let mut rows: Vec<[String; 8]> = vec![];
+    let mut errors: Vec<String> = vec![];
+    let mut skipped = 0;
+    for run in runs {
+        if let Err(Outcome::Missing(_)) = run.parse {
+            skipped += 1;
+            continue;
+        }
+        for part_run in &run.parts {
+            let (answer, time, peak, allocs) = match &part_run.outcome {
+                Outcome::Solved(answer) => {
+                    if let Verdict::Wrong { expected } = &part_run.verdict {
+                        let label = run.input.label(part_run.part);
+                        errors.push(format!(
+                            "Day {} {label}: {answer} is WRONG, expected {expected}",
+                            run.day
+                        ));
+                    }
+                    (
+                        answer.to_string(),
+                        format!("{:.2?}", part_run.elapsed),
+                        Bytes(part_run.heap.peak_bytes).to_string(),
+                        part_run.heap.allocations.to_string(),
+                    )
+                }
+                other => {
+                    let label = run.input.label(part_run.part);
+                    errors.push(format!("Day {} {label}: {other}", run.day));
+                    let dash = || "-".to_string();
+                    (dash(), dash(), dash(), dash())
+                }
+            };
+            rows.push([
+                run.day.to_string(),
+                part_run.part.number().to_string(),
+                run.input.to_string(),
+                answer,
+                time,
+                peak,
+                allocs,
+                part_run.status().to_string(),
+            ]);
+        }
+    }
+
+    // Numbers are right-aligned, text is left-aligned
+    print_columns("", HEADERS, &rows, &[0, 1, 4, 5, 6]);
+    println!();
+    for error in &errors {
+        println!("{error}");
+    }
+    if skipped > 0 {
+        println!("Skipped {skipped} missing input(s)");
+    }
+    println!("Total: {:.2?}", total);
+}
+
+/// Prints the answer and time of each variant of one part next to the first
+/// (default) one. Returns whether every variant solved it with the same answer.
+pub fn print_cross_check(label: &str, runs: &[VariantRun]) -> bool {
+    let Some(reference) = runs.first() else {
+        println!("{label}: no variants");
+        return true;
+    };
+    let rows: Vec<[String; 4]> = runs
+        .iter()
+        .map(|run| {
+            let relative = match (&run.outcome, &reference.outcome) {
+                (Outcome::Solved(_), Outcome::Solved(_)) => format!(
+                    "{:.2}x",
+                    run.elapsed.as_secs_f64() / reference.elapsed.as_secs_f64().max(f64::EPSILON)
+                ),
+                _ => "-".to_string(),
+            };
+            [
+                run.name.to_string(),
+                run.outcome.to_string(),
+                format!("{:.2?}", run.elapsed),
+                relative,
+            ]
+        })
+        .collect();
+
+    println!("{label}:");
+    print_columns(
+        "\t",
+        ["Variant", "Answer", "Time", "Relative"],
+        &rows,
+        &[2, 3],
+    );
+
+    let agree = runs
+        .iter()
+        .all(|run| match (&run.outcome, &reference.outcome) {
+            (Outcome::Solved(answer), Outcome::Solved(expected)) => answer == expected,
+            _ => false,
+        });
+    if !agree {
+        println!("\tDISAGREE! The variants of {label} don't all give the same answer");
+    }
+    agree
+}
+
+/// One solved (or failed) part, as printed by `--format json|csv`
+#[derive(Serialize)]
+pub struct Record {
+    day: u32,
+    part: u8,
+    input: String,
+    answer: Option<String>,
+    duration_ns: u128,
+    peak_heap_bytes: usize,
+    allocations: usize,
+    status: &'static str,
+    error: Option<String>,
+}
+
+const RECORD_FIELDS: [&str; 9] = [
+    "day",
+    "part",
+    "input",
+    "answer",
+    "duration_ns",
+    "peak_heap_bytes",
+    "allocations",
+    "status",
+    "error",
+];
+
+pub fn records(runs: &[InputRun]) -> Vec<Record> {
+    runs.iter()
+        .flat_map(|run| {
+            run.parts.iter().map(|part_run| {
+                let (answer, error) = match &part_run.outcome {
+                    Outcome::Solved(answer) => (Some(answer.to_string()), None),
+                    other => (None, Some(other.to_string())),
+                };
+                Record {
+                    day: run.day,
+                    part: part_run.part.number(),
+                    input: run.input.to_string(),
+                    answer,
+                    duration_ns: part_run.elapsed.as_nanos(),
+                    peak_heap_bytes: part_run.heap.peak_bytes,
+                    allocations: part_run.heap.allocations,
+                    status: part_run.status(),
+                    error,
+                }
+            })
+        })
+        .collect()
+}
+
+pub fn print_json(runs: &[InputRun]) {
+    let json = serde_json::to_string_pretty(&records(runs)).expect("Records are plain data");
+    println!("{json}");
+}
+
+// Quotes a field only when it would otherwise break the row
+fn csv_field(field: &str) -> String {
+    if field.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", field.replace('"', "\"\""))
+    } else {
+        field.to_string()
+    }
+}
+
+pub fn print_csv(runs: &[InputRun]) {
+    println!("{}", RECORD_FIELDS.join(","));
+    for record in records(runs) {
+        let fields = [
+            record.day.to_string(),
+            record.part.to_string(),
+            record.input,
+            record.answer.unwrap_or_default(),
+            record.duration_ns.to_string(),
+            record.peak_heap_bytes.to_string(),
+            record.allocations.to_string(),
+            record.status.to_string(),
+            record.error.unwrap_or_default(),
+        ];
+        let row: Vec<String> = fields.iter().map(|f| csv_field(f)).collect();
+        println!("{}", row.join(","));
+    }
+}
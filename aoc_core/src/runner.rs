@@ -0,0 +1,377 @@
+use std::any::Any;
+use std::convert::Infallible;
+use std::fmt;
+use std::fs::read_to_string;
+use std::io::{stdin, Read};
+use std::panic::{catch_unwind, AssertUnwindSafe};
+use std::path::{absolute, PathBuf};
+use std::str::FromStr;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::thread;
+use std::time::{Duration, Instant};
+
+use serde::{Deserialize, Serialize};
+
+use crate::alloc::{measure, HeapUsage};
+use crate::answers::Verdict;
+use crate::solution::{Answer, DynSolution, Part};
+use crate::utils::{read_test_file, test_file_path, FileNotFound, ACTUAL, EXAMPLE};
+
+/// Where a day's input comes from: its fixture files, any other file, or stdin
+#[derive(Clone, PartialEq, Debug, Serialize, Deserialize)]
+pub enum InputKind {
+    Example,
+    Actual,
+    File(PathBuf),
+    Stdin,
+}
+
+impl InputKind {
+    pub const INPUTS: [Self; 2] = [Self::Example, Self::Actual];
+
+    /// Inverse of `from_str`, as passed to `--input`
+    pub fn to_arg(&self) -> String {
+        match self {
+            InputKind::Example => "example".to_string(),
+            InputKind::Actual => "actual".to_string(),
+            InputKind::File(path) => path.display().to_string(),
+            InputKind::Stdin => "-".to_string(),
+        }
+    }
+
+    // Fixture inputs are looked up per day, the others are the same for any day
+    pub fn is_fixture(&self) -> bool {
+        matches!(self, InputKind::Example | InputKind::Actual)
+    }
+
+    /// File the input of `day` is read from, whether it exists or not
+    pub fn file_path(&self, day: u32) -> Option<PathBuf> {
+        match self {
+            InputKind::Example => Some(test_file_path(EXAMPLE, &format!("{day:02}")).into()),
+            InputKind::Actual => Some(test_file_path(ACTUAL, &format!("{day:02}")).into()),
+            InputKind::File(path) => Some(path.clone()),
+            InputKind::Stdin => None,
+        }
+    }
+
+    pub fn read(&self, day: u32) -> Result<String, FileNotFound> {
+        match self {
+            InputKind::Example => read_test_file(EXAMPLE, &format!("{day:02}")),
+            InputKind::Actual => read_test_file(ACTUAL, &format!("{day:02}")),
+            InputKind::File(path) => read_to_string(path).map_err(|_| FileNotFound),
+            InputKind::Stdin => {
+                let mut text = String::new();
+                match stdin().read_to_string(&mut text) {
+                    Ok(_) => Ok(text),
+                    Err(_) => Err(FileNotFound),
+                }
+            }
+        }
+    }
+
+    // Keeps the names the menu has always printed, e.g. "Example" and "Actual v2"
+    pub fn label(&self, part: Part) -> String {
+        match part {
+            Part::One => self.to_string(),
+            Part::Two => format!("{self} v2"),
+        }
+    }
+}
+
+/// Parses the value of `--input`: `example`, `actual`, `-` for stdin, or a path.
+/// Paths are made absolute, since the runner works from the year's directory
+impl FromStr for InputKind {
+    type Err = Infallible;
+
+    fn from_str(arg: &str) -> Result<Self, Self::Err> {
+        Ok(match arg {
+            "example" => InputKind::Example,
+            "actual" => InputKind::Actual,
+            "-" => InputKind::Stdin,
+            path => InputKind::File(absolute(path).unwrap_or_else(|_| PathBuf::from(path))),
+        })
+    }
+}
+
+impl fmt::Display for InputKind {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            InputKind::Example => write!(f, "Example"),
+            InputKind::Actual => write!(f, "Actual"),
+            InputKind::File(path) => write!(f, "{}", path.display()),
+            InputKind::Stdin => write!(f, "stdin"),
+        }
+    }
+}
+
+#[derive(Clone, Serialize, Deserialize)]
+pub enum Outcome {
+    Solved(Answer),
+    Missing(FileNotFound),
+    Panicked(String),
+    TimedOut(Duration),
+    OutOfMemory,
+}
+
+impl Outcome {
+    pub fn status(&self) -> &'static str {
+        match self {
+            Outcome::Solved(_) => "ok",
+            Outcome::Missing(_) => "missing",
+            Outcome::Panicked(_) => "error",
+            Outcome::TimedOut(_) => "TIMEOUT",
+            Outcome::OutOfMemory => "OOM",
+        }
+    }
+}
+
+impl fmt::Display for Outcome {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Outcome::Solved(answer) => write!(f, "{answer}"),
+            Outcome::Missing(err) => write!(f, "ERROR! {err}"),
+            Outcome::Panicked(msg) => write!(f, "ERROR! {msg}"),
+            Outcome::TimedOut(limit) => write!(f, "TIMEOUT! gave up after {:.2?}", limit),
+            Outcome::OutOfMemory => write!(f, "OOM! ran out of memory"),
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize)]
+pub struct PartRun {
+    pub part: Part,
+    pub outcome: Outcome,
+    pub elapsed: Duration,
+    pub heap: HeapUsage,
+    pub verdict: Verdict,
+}
+
+impl PartRun {
+    pub fn failed(part: Part, outcome: Outcome) -> PartRun {
+        PartRun {
+            part,
+            outcome,
+            elapsed: Duration::ZERO,
+            heap: HeapUsage::default(),
+            verdict: Verdict::Unknown,
+        }
+    }
+
+    pub fn status(&self) -> &'static str {
+        match self.outcome {
+            Outcome::Solved(_) => self.verdict.status(),
+            _ => self.outcome.status(),
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize)]
+pub struct InputRun {
+    pub day: u32,
+    pub input: InputKind,
+    pub parse: Result<Duration, Outcome>,
+    pub parts: Vec<PartRun>,
+}
+
+pub fn panic_message(payload: Box<dyn Any + Send>) -> String {
+    if let Some(msg) = payload.downcast_ref::<&str>() {
+        msg.to_string()
+    } else if let Some(msg) = payload.downcast_ref::<String>() {
+        msg.clone()
+    } else {
+        "solution panicked".to_string()
+    }
+}
+
+pub fn failed_run(day: u32, input: &InputKind, parts: &[Part], outcome: Outcome) -> InputRun {
+    InputRun {
+        day,
+        input: input.clone(),
+        parts: parts
+            .iter()
+            .map(|part| PartRun::failed(*part, outcome.clone()))
+            .collect(),
+        parse: Err(outcome),
+    }
+}
+
+// Parses `text` as `input`, returning the parsed input and how long parsing took
+fn parse(
+    solution: &dyn DynSolution,
+    input: &InputKind,
+    text: &str,
+) -> Result<(Box<dyn Any>, Duration), Outcome> {
+    let now = Instant::now();
+    let example = *input == InputKind::Example;
+    match catch_unwind(AssertUnwindSafe(|| solution.parse(text, example))) {
+        Ok(parsed) => Ok((parsed, now.elapsed())),
+        Err(payload) => Err(Outcome::Panicked(panic_message(payload))),
+    }
+}
+
+fn read_and_parse(
+    solution: &dyn DynSolution,
+    input: &InputKind,
+) -> Result<(Box<dyn Any>, Duration), Outcome> {
+    let text = input.read(solution.day()).map_err(Outcome::Missing)?;
+    parse(solution, input, &text)
+}
+
+fn solve_part(
+    solution: &dyn DynSolution,
+    parsed: &dyn Any,
+    part: Part,
+    variant: Option<&str>,
+) -> (Outcome, Duration, HeapUsage) {
+    let now = Instant::now();
+    let (result, heap) =
+        measure(|| catch_unwind(AssertUnwindSafe(|| solution.solve(parsed, part, variant))));
+    let elapsed = now.elapsed();
+    match result {
+        Ok(answer) => (Outcome::Solved(answer), elapsed, heap),
+        Err(payload) => (Outcome::Panicked(panic_message(payload)), elapsed, heap),
+    }
+}
+
+/// Reads and parses one input for a day, then solves each of `parts` with it,
+/// using the implementation called `variant` if given.
+/// Panics inside the solution are caught and reported as `Outcome::Panicked`.
+pub fn run_input(
+    solution: &dyn DynSolution,
+    input: &InputKind,
+    parts: &[Part],
+    variant: Option<&str>,
+) -> InputRun {
+    run_parsed(
+        solution,
+        input,
+        read_and_parse(solution, input),
+        parts,
+        variant,
+    )
+}
+
+/// Same as `run_input` for the text of `input` that is already in memory
+pub fn run_text(
+    solution: &dyn DynSolution,
+    input: &InputKind,
+    text: &str,
+    parts: &[Part],
+    variant: Option<&str>,
+) -> InputRun {
+    run_parsed(
+        solution,
+        input,
+        parse(solution, input, text),
+        parts,
+        variant,
+    )
+}
+
+fn run_parsed(
+    solution: &dyn DynSolution,
+    input: &InputKind,
+    parsed: Result<(Box<dyn Any>, Duration), Outcome>,
+    parts: &[Part],
+    variant: Option<&str>,
+) -> InputRun {
+    let (parsed, parse_time) = match parsed {
+        Ok(parsed) => parsed,
+        Err(outcome) => return failed_run(solution.day(), input, parts, outcome),
+    };
+
+    let parts = parts
+        .iter()
+        .map(|part| {
+            let (outcome, elapsed, heap) = solve_part(solution, parsed.as_ref(), *part, variant);
+            PartRun {
+                part: *part,
+                outcome,
+                elapsed,
+                heap,
+                verdict: Verdict::Unknown,
+            }
+        })
+        .collect();
+
+    InputRun {
+        day: solution.day(),
+        input: input.clone(),
+        parse: Ok(parse_time),
+        parts,
+    }
+}
+
+pub struct VariantRun {
+    pub name: &'static str,
+    pub outcome: Outcome,
+    pub elapsed: Duration,
+}
+
+/// Parses `input` once and solves each of `parts` with every variant of it.
+/// A part with only its default implementation has no runs.
+pub fn cross_check(
+    solution: &dyn DynSolution,
+    input: &InputKind,
+    parts: &[Part],
+) -> Result<Vec<(Part, Vec<VariantRun>)>, Outcome> {
+    let (parsed, _) = read_and_parse(solution, input)?;
+    Ok(parts
+        .iter()
+        .map(|part| {
+            let variants = solution.variants(*part);
+            if variants.len() < 2 {
+                return (*part, vec![]);
+            }
+            let runs = variants
+                .into_iter()
+                .map(|name| {
+                    let (outcome, elapsed, _) =
+                        solve_part(solution, parsed.as_ref(), *part, Some(name));
+                    VariantRun {
+                        name,
+                        outcome,
+                        elapsed,
+                    }
+                })
+                .collect();
+            (*part, runs)
+        })
+        .collect())
+}
+
+/// Calls `f` on every task using up to `jobs` threads, returning the results in
+/// the same order as `tasks`
+pub fn run_parallel<T: Sync, R: Send>(
+    tasks: &[T],
+    jobs: usize,
+    f: impl Fn(&T) -> R + Sync,
+) -> Vec<R> {
+    if jobs <= 1 {
+        return tasks.iter().map(f).collect();
+    }
+
+    let next = AtomicUsize::new(0);
+    let mut results: Vec<(usize, R)> = thread::scope(|scope| {
+        let workers: Vec<_> = (0..jobs.min(tasks.len()))
+            .map(|_| {
+                scope.spawn(|| {
+                    let mut done = vec![];
+                    loop {
+                        let i = next.fetch_add(1, Ordering::Relaxed);
+                        let Some(task) = tasks.get(i) else {
+                            break done;
+                        };
+                        done.push((i, f(task)));
+                    }
+                })
+            })
+            .collect();
+        workers
+            .into_iter()
+            .flat_map(|w| w.join().expect("Panics are caught by the runner"))
+            .collect()
+    });
+    results.sort_by_key(|(i, _)| *i);
+    results.into_iter().map(|(_, r)| r).collect()
+}
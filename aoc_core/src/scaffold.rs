@@ -0,0 +1,153 @@
+use std::fs::{read_to_string, write, OpenOptions};
+use std::path::Path;
+
+use crate::utils::EXAMPLE;
+
+// Each year keeps its own `day00.rs`, compiled and tested along with its other
+// days so it can't go stale
+const TEMPLATE: &str = "src/day00.rs";
+const LIB: &str = "src/lib.rs";
+
+/// Fills in the template for `day`. The template's tests expect the answers
+/// to its own example, so they are ignored until the new day's example and
+/// answers are filled in.
+fn render(template: &str, day: u32) -> String {
+    let ignore = format!(
+        "    #[test]\n    #[ignore = \"TODO: add the example to {EXAMPLE}/day{day:02}.txt and its expected answer\"]\n"
+    );
+    template
+        .replace("Day00", &format!("Day{day:02}"))
+        .replace("day0", &format!("day{day}"))
+        .replace("DAY: u32 = 0;", &format!("DAY: u32 = {day};"))
+        .replace("    #[test]\n", &ignore)
+}
+
+/// Adds `day` to the module list and to `SOLUTIONS` in `lib.rs`
+fn register(lib: &str, day: u32) -> Result<String, String> {
+    let module = format!("pub mod day{day:02};");
+    let entry = format!("    &day{day:02}::Day{day:02},");
+    if lib.lines().any(|l| l == module) {
+        return Err(format!("Day {day} is already registered in {LIB}"));
+    }
+
+    let mut lines: Vec<String> = lib.lines().map(String::from).collect();
+
+    // Uncomment the placeholder if there is one, otherwise add it after the
+    // last day before it
+    let placeholder = format!("// {module}");
+    match lines.iter().position(|l| *l == placeholder) {
+        Some(i) => lines[i] = module,
+        None => {
+            let i = lines
+                .iter()
+                .rposition(|l| l.starts_with("pub mod day") && l.as_str() < module.as_str())
+                .or_else(|| lines.iter().position(|l| l.starts_with("pub mod day")))
+                .ok_or(format!("Could not find the day modules in {LIB}"))?;
+            lines.insert(i + 1, module);
+        }
+    }
+
+    let start = lines
+        .iter()
+        .position(|l| l.starts_with("pub const SOLUTIONS"))
+        .ok_or(format!("Could not find SOLUTIONS in {LIB}"))?;
+    let end = start
+        + lines[start..]
+            .iter()
+            .position(|l| l == "];")
+            .ok_or(format!("Could not find the end of SOLUTIONS in {LIB}"))?;
+    // Keep the registry in day order
+    let i = lines[start + 1..end]
+        .iter()
+        .position(|l| l.as_str() > entry.as_str())
+        .map_or(end, |i| start + 1 + i);
+    lines.insert(i, entry);
+
+    Ok(lines.join("\n") + "\n")
+}
+
+/// Creates `src/dayNN.rs` from the template and an empty example input, and
+/// registers the new day. Returns the paths that were written.
+pub fn new_day(day: u32) -> Result<Vec<String>, String> {
+    let source = format!("src/day{day:02}.rs");
+    let example = format!("{EXAMPLE}/day{day:02}.txt");
+    if Path::new(&source).exists() {
+        return Err(format!("{source} already exists"));
+    }
+
+    let template =
+        read_to_string(TEMPLATE).map_err(|e| format!("Could not read {TEMPLATE}: {e}"))?;
+    let lib = read_to_string(LIB).map_err(|e| format!("Could not read {LIB}: {e}"))?;
+    let lib = register(&lib, day)?;
+
+    write(&source, render(&template, day)).map_err(|e| format!("Could not write {source}: {e}"))?;
+    // Never truncate an example that was already saved
+    OpenOptions::new()
+        .create(true)
+        .append(true)
+        .open(&example)
+        .map_err(|e| format!("Could not create {example}: {e}"))?;
+    write(LIB, lib).map_err(|e| format!("Could not write {LIB}: {e}"))?;
+
+    Ok(vec![source, example, LIB.to_string()])
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const LIB_RS: &str = "pub mod day01;
+pub mod day02;
+// pub mod day03;
+mod cli;
+
+pub const SOLUTIONS: &[&dyn DynSolution] = &[
+    &day01::Day01,
+    &day02::Day02,
+];
+";
+
+    #[test]
+    fn test_register() {
+        let lib = register(LIB_RS, 3).unwrap();
+        assert!(lib.contains("pub mod day02;\npub mod day03;\nmod cli;"));
+        assert!(lib.contains("    &day02::Day02,\n    &day03::Day03,\n];"));
+        assert!(register(&lib, 3).is_err());
+    }
+
+    #[test]
+    fn test_register_without_placeholder() {
+        let lib = register(LIB_RS, 10).unwrap();
+        assert!(lib.contains("pub mod day02;\npub mod day10;\n// pub mod day03;"));
+        assert!(lib.contains("    &day02::Day02,\n    &day10::Day10,\n];"));
+    }
+
+    const DAY00_RS: &str = "pub struct Day00;
+
+impl Solution for Day00 {
+    const DAY: u32 = 0;
+}
+
+fn day0_v2(input: &Input) -> u32 {
+
+#[cfg(test)]
+mod tests {
+    #[test]
+    fn test_example() {
+        assert_eq!(solve::<Day00>(EXAMPLE, Part::One).unwrap(), 10);
+    }
+}
+";
+
+    #[test]
+    fn test_render() {
+        let source = render(DAY00_RS, 17);
+        assert!(source.contains("pub struct Day17;"));
+        assert!(source.contains("const DAY: u32 = 17;"));
+        assert!(source.contains("fn day17_v2(input: &Input)"));
+        assert!(!source.contains("day0"));
+        assert!(source.contains(
+            "    #[test]\n    #[ignore = \"TODO: add the example to tests/day17.txt and its expected answer\"]\n    fn test_example() {"
+        ));
+    }
+}
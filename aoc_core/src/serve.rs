@@ -0,0 +1,428 @@
+use std::io::{self, BufRead, BufReader, ErrorKind, Write};
+use std::net::{TcpListener, TcpStream};
+use std::sync::{mpsc, Mutex};
+use std::thread;
+use std::time::Duration;
+
+use serde::Serialize;
+use serde_json::json;
+
+use crate::runner::{run_text, InputKind, InputRun, Outcome};
+use crate::solution::{Part, Year};
+use crate::worker::{run_text_isolated, Limits};
+
+// Inputs are a few KiB, anything this big is a mistake
+const MAX_BODY: usize = 16 * 1024 * 1024;
+// How long a client may take to send its request or read the response, so a
+// stalled one can't hold a worker forever
+const IO_TIMEOUT: Duration = Duration::from_secs(30);
+
+struct Request {
+    method: String,
+    path: String,
+    body: String,
+}
+
+/// What `POST /solve/{day}/{part}` answers with, durations are in nanoseconds
+#[derive(Serialize)]
+struct Solved {
+    day: u32,
+    part: u8,
+    answer: Option<String>,
+    parse_ns: Option<u128>,
+    solve_ns: Option<u128>,
+    status: &'static str,
+    error: Option<String>,
+}
+
+impl Solved {
+    fn new(run: &InputRun, part: Part) -> Solved {
+        let mut solved = Solved {
+            day: run.day,
+            part: part.number(),
+            answer: None,
+            parse_ns: run.parse.as_ref().ok().map(|d| d.as_nanos()),
+            solve_ns: None,
+            status: "ok",
+            error: None,
+        };
+        let outcome = match (&run.parse, run.parts.first()) {
+            (Err(outcome), _) => {
+                solved.error = Some(format!("could not parse the input: {}", message(outcome)));
+                outcome
+            }
+            (Ok(_), Some(part_run)) => {
+                solved.solve_ns = Some(part_run.elapsed.as_nanos());
+                solved.error = match &part_run.outcome {
+                    Outcome::Solved(_) => None,
+                    outcome => Some(message(outcome)),
+                };
+                &part_run.outcome
+            }
+            (Ok(_), None) => unreachable!("one part is always run"),
+        };
+        solved.status = outcome.status();
+        if let Outcome::Solved(answer) = outcome {
+            solved.answer = Some(answer.to_string());
+        }
+        solved
+    }
+}
+
+// The outcome without the "ERROR!" the terminal output starts with
+fn message(outcome: &Outcome) -> String {
+    match outcome {
+        Outcome::Panicked(msg) => msg.clone(),
+        Outcome::Missing(err) => err.to_string(),
+        outcome => outcome.to_string(),
+    }
+}
+
+fn reason(status: u16) -> &'static str {
+    match status {
+        200 => "OK",
+        400 => "Bad Request",
+        404 => "Not Found",
+        405 => "Method Not Allowed",
+        408 => "Request Timeout",
+        411 => "Length Required",
+        413 => "Content Too Large",
+        422 => "Unprocessable Content",
+        _ => "Internal Server Error",
+    }
+}
+
+fn error(status: u16, msg: &str) -> (u16, String) {
+    (status, json!({ "error": msg }).to_string())
+}
+
+// A read that timed out is reported as such, anything else as a bad request
+fn read_error(err: io::Error, msg: &str) -> (u16, String) {
+    match err.kind() {
+        ErrorKind::WouldBlock | ErrorKind::TimedOut => error(408, "timed out reading the request"),
+        _ => error(400, msg),
+    }
+}
+
+/// Reads one request from `reader`. `writer` is the other end of the
+/// connection, clients that send `Expect: 100-continue` wait for a reply on
+/// it before sending the body.
+fn read_request(
+    reader: &mut impl BufRead,
+    writer: &mut impl Write,
+) -> Result<Request, (u16, String)> {
+    let bad_request = |msg: &str| error(400, msg);
+    let mut line = String::new();
+    reader
+        .read_line(&mut line)
+        .map_err(|e| read_error(e, "could not read the request"))?;
+    let mut words = line.split_whitespace();
+    let (Some(method), Some(path)) = (words.next(), words.next()) else {
+        return Err(bad_request("malformed request line"));
+    };
+    let (method, path) = (method.to_string(), path.to_string());
+
+    let mut length = None;
+    let mut expect_continue = false;
+    loop {
+        line.clear();
+        reader
+            .read_line(&mut line)
+            .map_err(|e| read_error(e, "could not read the headers"))?;
+        let header = line.trim_end();
+        if header.is_empty() {
+            break;
+        }
+        let Some((name, value)) = header.split_once(':') else {
+            return Err(bad_request("malformed header"));
+        };
+        let value = value.trim();
+        match name.to_ascii_lowercase().as_str() {
+            "content-length" => {
+                length = Some(
+                    value
+                        .parse::<usize>()
+                        .map_err(|_| bad_request("invalid Content-Length"))?,
+                )
+            }
+            "transfer-encoding" => {
+                return Err(error(
+                    411,
+                    "chunked bodies are not supported, send a Content-Length",
+                ))
+            }
+            "expect" => expect_continue = value.eq_ignore_ascii_case("100-continue"),
+            _ => {}
+        }
+    }
+
+    let length = length.unwrap_or(0);
+    if length > MAX_BODY {
+        return Err(error(413, "the input is too large"));
+    }
+    if expect_continue && length > 0 {
+        let _ = writer.write_all(b"HTTP/1.1 100 Continue\r\n\r\n");
+    }
+    let mut body = vec![0; length];
+    reader
+        .read_exact(&mut body)
+        .map_err(|e| read_error(e, "the body is shorter than its Content-Length"))?;
+    let body = String::from_utf8(body).map_err(|_| bad_request("the input is not UTF-8"))?;
+    Ok(Request { method, path, body })
+}
+
+/// Solves the request's body with the day and part in its path, returning
+/// the status code and JSON body of the response. The body is solved as the
+/// day's example with `?example`.
+fn route(year: &Year, limits: Limits, request: &Request) -> (u16, String) {
+    let (path, query) = request
+        .path
+        .split_once('?')
+        .unwrap_or((request.path.as_str(), ""));
+    let input = if query.split('&').any(|q| q == "example") {
+        InputKind::Example
+    } else {
+        InputKind::Stdin
+    };
+    let Some(rest) = path.strip_prefix("/solve/") else {
+        return error(404, "the only endpoint is POST /solve/{day}/{part}");
+    };
+    if request.method != "POST" {
+        return error(405, "use POST with the input as the body");
+    }
+    let Some((day, part)) = rest.split_once('/') else {
+        return error(404, "the only endpoint is POST /solve/{day}/{part}");
+    };
+    let Ok(day) = day.parse::<u32>() else {
+        return error(400, "the day must be a number");
+    };
+    let part = match part {
+        "1" => Part::One,
+        "2" => Part::Two,
+        _ => return error(400, "the part must be 1 or 2"),
+    };
+    let Some(solution) = year.find_solution(day) else {
+        return error(
+            404,
+            &format!("day {day} of {} is not implemented", year.year),
+        );
+    };
+
+    let run = if limits.is_unlimited() {
+        run_text(solution, &input, &request.body, &[part], None)
+    } else {
+        run_text_isolated(
+            year.year,
+            solution,
+            &input,
+            &request.body,
+            &[part],
+            None,
+            limits,
+        )
+    };
+    let solved = Solved::new(&run, part);
+    let status = if solved.error.is_none() { 200 } else { 422 };
+    let json = serde_json::to_string(&solved).expect("Solved is plain data");
+    (status, json)
+}
+
+fn handle(year: &Year, limits: Limits, stream: TcpStream) {
+    let timeouts = stream
+        .set_read_timeout(Some(IO_TIMEOUT))
+        .and(stream.set_write_timeout(Some(IO_TIMEOUT)));
+    if timeouts.is_err() {
+        return;
+    }
+    let Ok(mut writer) = stream.try_clone() else {
+        return;
+    };
+    let mut reader = BufReader::new(stream);
+    let (status, body) = match read_request(&mut reader, &mut writer) {
+        Ok(request) => {
+            let (status, body) = route(year, limits, &request);
+            println!("{} {} {status}", request.method, request.path);
+            (status, body)
+        }
+        Err(response) => response,
+    };
+    let _ = write!(
+        writer,
+        "HTTP/1.1 {status} {}\r\nContent-Type: application/json\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{body}",
+        reason(status),
+        body.len()
+    );
+}
+
+/// Answers `POST /solve/{day}/{part}` requests on `host:port` until the
+/// process is stopped. Up to `jobs` connections are handled at the same time,
+/// each solved under `limits` like the `run` command.
+pub fn serve(
+    year: &Year,
+    host: &str,
+    port: u16,
+    jobs: usize,
+    limits: Limits,
+) -> Result<(), String> {
+    let listener = TcpListener::bind((host, port))
+        .map_err(|e| format!("Could not listen on {host}:{port}: {e}"))?;
+    let addr = listener.local_addr().map_err(|e| e.to_string())?;
+    println!(
+        "Solving {} on http://{addr}/solve/{{day}}/{{part}}",
+        year.year
+    );
+    // Connections beyond the ones being handled wait in the listen backlog
+    let (sender, receiver) = mpsc::sync_channel::<TcpStream>(jobs);
+    let receiver = Mutex::new(receiver);
+    thread::scope(|s| {
+        for _ in 0..jobs {
+            s.spawn(|| loop {
+                let next = receiver.lock().expect("a worker panicked").recv();
+                let Ok(stream) = next else {
+                    return;
+                };
+                handle(year, limits, stream);
+            });
+        }
+        for stream in listener.incoming().flatten() {
+            if sender.send(stream).is_err() {
+                break;
+            }
+        }
+        drop(sender);
+    });
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::solution::{DynSolution, Solution};
+
+    struct Sum;
+
+    impl Solution for Sum {
+        const DAY: u32 = 1;
+        const TITLE: &'static str = "Sum";
+
+        type Input = Vec<u32>;
+        type Answer = u32;
+
+        fn parse(input: &str) -> Vec<u32> {
+            input
+                .lines()
+                .map(|l| l.parse().expect("not a number"))
+                .collect()
+        }
+
+        // The example only has room for its first number
+        fn parse_example(input: &str) -> Vec<u32> {
+            <Self as Solution>::parse(input.lines().next().unwrap_or_default())
+        }
+
+        fn part1(input: &Vec<u32>) -> u32 {
+            input.iter().sum()
+        }
+
+        fn part2(input: &Vec<u32>) -> u32 {
+            input.iter().copied().max().expect("no numbers")
+        }
+    }
+
+    const YEAR: Year = Year {
+        year: 2024,
+        dir: "",
+        manifest_dir: "",
+        target: "",
+        solutions: &[&Sum as &dyn DynSolution],
+    };
+    const UNLIMITED: Limits = Limits {
+        timeout: None,
+        memory_mb: None,
+    };
+
+    fn post(path: &str, body: &str) -> (u16, serde_json::Value) {
+        let request = Request {
+            method: "POST".to_string(),
+            path: path.to_string(),
+            body: body.to_string(),
+        };
+        let (status, json) = route(&YEAR, UNLIMITED, &request);
+        (status, serde_json::from_str(&json).unwrap())
+    }
+
+    #[test]
+    fn test_read_request() {
+        let raw = "POST /solve/1/2 HTTP/1.1\r\nHost: x\r\ncontent-length: 4\r\nExpect: 100-continue\r\n\r\n1\n2\nextra";
+        let mut written = vec![];
+        let request = read_request(&mut raw.as_bytes(), &mut written).unwrap();
+        assert_eq!(request.method, "POST");
+        assert_eq!(request.path, "/solve/1/2");
+        assert_eq!(request.body, "1\n2\n");
+        assert_eq!(written, b"HTTP/1.1 100 Continue\r\n\r\n");
+
+        let raw = "POST /solve/1/2 HTTP/1.1\r\nContent-Length: 10\r\n\r\n1\n2\n";
+        assert_eq!(
+            read_request(&mut raw.as_bytes(), &mut vec![])
+                .err()
+                .unwrap()
+                .0,
+            400
+        );
+        let raw = "POST /solve/1/2 HTTP/1.1\r\nTransfer-Encoding: chunked\r\n\r\n";
+        assert_eq!(
+            read_request(&mut raw.as_bytes(), &mut vec![])
+                .err()
+                .unwrap()
+                .0,
+            411
+        );
+    }
+
+    // Reads like a socket whose read timeout ran out
+    struct Stalled;
+
+    impl io::Read for Stalled {
+        fn read(&mut self, _: &mut [u8]) -> io::Result<usize> {
+            Err(ErrorKind::WouldBlock.into())
+        }
+    }
+
+    #[test]
+    fn test_read_timeout() {
+        let mut reader = BufReader::new(Stalled);
+        assert_eq!(read_request(&mut reader, &mut vec![]).err().unwrap().0, 408);
+    }
+
+    #[test]
+    fn test_route() {
+        let (status, json) = post("/solve/1/1", "1\n2\n3\n");
+        assert_eq!(status, 200);
+        assert_eq!(json["answer"], "6");
+        assert_eq!(json["status"], "ok");
+        assert!(json["parse_ns"].is_u64() && json["solve_ns"].is_u64());
+        assert_eq!(post("/solve/1/2", "1\n5\n3\n").1["answer"], "5");
+
+        let (status, json) = post("/solve/1/1", "1\nfoo\n");
+        assert_eq!(status, 422);
+        assert_eq!(json["status"], "error");
+        assert!(json["answer"].is_null() && json["solve_ns"].is_null());
+        assert!(json["error"]
+            .as_str()
+            .unwrap()
+            .contains("could not parse the input"));
+
+        let (status, json) = post("/solve/1/2", "");
+        assert_eq!(status, 422);
+        assert!(json["parse_ns"].is_u64());
+        assert_eq!(json["error"], "no numbers");
+
+        assert_eq!(post("/solve/2/1", "").0, 404);
+        assert_eq!(post("/solve/1/3", "").0, 400);
+        assert_eq!(post("/solve/x/1", "").0, 400);
+        assert_eq!(post("/answer/1/1", "").0, 404);
+
+        assert_eq!(post("/solve/1/1?example", "1\n2\n3\n").1["answer"], "1");
+        assert_eq!(post("/solve/1/1?other", "1\n2\n3\n").1["answer"], "6");
+    }
+}
@@ -0,0 +1,234 @@
+use std::{any::Any, fmt};
+
+use serde::{Deserialize, Serialize};
+
+use crate::utils::{read_test_file, FileNotFound, EXAMPLE};
+
+#[derive(Clone, Copy, PartialEq, Debug, Serialize, Deserialize)]
+pub enum Part {
+    One,
+    Two,
+}
+
+impl Part {
+    pub fn number(&self) -> u8 {
+        match self {
+            Part::One => 1,
+            Part::Two => 2,
+        }
+    }
+}
+
+/// The answer to a part, whatever type the day computed it as. Integers are
+/// widened to 128 bits and some answers are text, e.g. comma-separated lists.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub enum Answer {
+    Signed(i128),
+    Unsigned(u128),
+    Text(String),
+}
+
+// Every integer type fits in the 128-bit one of the same sign
+macro_rules! answer_from {
+    ($variant:ident, $wide:ty: $($t:ty),*) => {
+        $(impl From<$t> for Answer {
+            fn from(n: $t) -> Self {
+                Answer::$variant(n as $wide)
+            }
+        })*
+    };
+}
+
+answer_from!(Signed, i128: i8, i16, i32, i64, i128, isize);
+answer_from!(Unsigned, u128: u8, u16, u32, u64, u128, usize);
+
+impl From<String> for Answer {
+    fn from(text: String) -> Self {
+        Answer::Text(text)
+    }
+}
+
+impl From<&str> for Answer {
+    fn from(text: &str) -> Self {
+        Answer::Text(text.to_string())
+    }
+}
+
+impl PartialEq for Answer {
+    // The same number is the same answer, whatever its sign type
+    fn eq(&self, other: &Self) -> bool {
+        match (self, other) {
+            (Answer::Signed(a), Answer::Signed(b)) => a == b,
+            (Answer::Unsigned(a), Answer::Unsigned(b)) => a == b,
+            (Answer::Signed(a), Answer::Unsigned(b)) | (Answer::Unsigned(b), Answer::Signed(a)) => {
+                u128::try_from(*a).is_ok_and(|a| a == *b)
+            }
+            (Answer::Text(a), Answer::Text(b)) => a == b,
+            _ => false,
+        }
+    }
+}
+
+impl fmt::Display for Answer {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Answer::Signed(n) => write!(f, "{n}"),
+            Answer::Unsigned(n) => write!(f, "{n}"),
+            Answer::Text(text) => write!(f, "{text}"),
+        }
+    }
+}
+
+/// Converts between integer types, panicking when `n` doesn't fit so an
+/// overflow shows up as an error instead of a truncated, wrong answer
+pub fn checked<T, U>(n: T) -> U
+where
+    T: TryInto<U> + Copy + fmt::Display,
+{
+    n.try_into()
+        .unwrap_or_else(|_| panic!("{n} does not fit in {}", std::any::type_name::<U>()))
+}
+
+/// Name of the `part1`/`part2` implementation among a part's variants
+pub const DEFAULT_VARIANT: &str = "default";
+
+/// Another implementation of one part of a day, selected by `name`
+pub struct Variant<S: Solution + ?Sized> {
+    pub name: &'static str,
+    pub part: Part,
+    pub solve: fn(&S::Input) -> S::Answer,
+}
+
+/// A day's puzzle. Adding a new day means implementing this for the day's
+/// struct and adding it to `SOLUTIONS` in `lib.rs`.
+///
+/// The input is parsed once by `parse` and then borrowed by both parts.
+pub trait Solution: Sync + 'static {
+    const DAY: u32;
+    const TITLE: &'static str;
+    /// Alternative implementations kept around to compare against `part1`/`part2`
+    const VARIANTS: &'static [Variant<Self>] = &[];
+
+    type Input: 'static;
+    type Answer: Into<Answer>;
+
+    fn parse(input: &str) -> Self::Input;
+    /// Parses the puzzle's example, for days that solve it with other
+    /// settings than the actual input
+    fn parse_example(input: &str) -> Self::Input {
+        Self::parse(input)
+    }
+    fn part1(input: &Self::Input) -> Self::Answer;
+    fn part2(input: &Self::Input) -> Self::Answer;
+}
+
+/// Runs one part of a day against its file in `path` (`EXAMPLE` or `ACTUAL`).
+pub fn solve<S: Solution>(path: &str, part: Part) -> Result<S::Answer, FileNotFound> {
+    let text = read_test_file(path, &format!("{:02}", S::DAY))?;
+    let input = if path == EXAMPLE {
+        S::parse_example(&text)
+    } else {
+        S::parse(&text)
+    };
+    match part {
+        Part::One => Ok(S::part1(&input)),
+        Part::Two => Ok(S::part2(&input)),
+    }
+}
+
+/// Object-safe view of a `Solution`, so days with different input and answer
+/// types can live in the same registry.
+pub trait DynSolution: Sync {
+    fn day(&self) -> u32;
+    fn title(&self) -> &'static str;
+    /// Parses `input` with `Solution::parse_example` when `example` is set
+    fn parse(&self, input: &str, example: bool) -> Box<dyn Any>;
+    /// Names of the implementations of `part`, starting with `DEFAULT_VARIANT`
+    fn variants(&self, part: Part) -> Vec<&'static str>;
+    /// Solves `part` with the implementation called `variant`, or the default one
+    fn solve(&self, input: &dyn Any, part: Part, variant: Option<&str>) -> Answer;
+}
+
+impl<S: Solution> DynSolution for S {
+    fn day(&self) -> u32 {
+        S::DAY
+    }
+
+    fn title(&self) -> &'static str {
+        S::TITLE
+    }
+
+    fn parse(&self, input: &str, example: bool) -> Box<dyn Any> {
+        if example {
+            Box::new(S::parse_example(input))
+        } else {
+            Box::new(S::parse(input))
+        }
+    }
+
+    fn variants(&self, part: Part) -> Vec<&'static str> {
+        let others = S::VARIANTS.iter().filter(|v| v.part == part);
+        [DEFAULT_VARIANT]
+            .into_iter()
+            .chain(others.map(|v| v.name))
+            .collect()
+    }
+
+    fn solve(&self, input: &dyn Any, part: Part, variant: Option<&str>) -> Answer {
+        let input = input
+            .downcast_ref::<S::Input>()
+            .expect("Input was parsed by a different day");
+        match (variant.unwrap_or(DEFAULT_VARIANT), part) {
+            (DEFAULT_VARIANT, Part::One) => S::part1(input).into(),
+            (DEFAULT_VARIANT, Part::Two) => S::part2(input).into(),
+            (name, part) => {
+                let variant = S::VARIANTS
+                    .iter()
+                    .find(|v| v.name == name && v.part == part)
+                    .unwrap_or_else(|| panic!("No variant {name} for part {}", part.number()));
+                (variant.solve)(input).into()
+            }
+        }
+    }
+}
+
+/// One year's solutions, as registered with the `aoc` runner
+pub struct Year {
+    pub year: u32,
+    /// The year's crate, relative to the workspace root. Commands run from
+    /// there so each year keeps its own inputs, answers and benchmarks
+    pub dir: &'static str,
+    /// Where the year's crate was built from, used when the workspace can't
+    /// be found from the current directory
+    pub manifest_dir: &'static str,
+    /// Module path of the year's crate, which its days log under
+    pub target: &'static str,
+    pub solutions: &'static [&'static dyn DynSolution],
+}
+
+impl Year {
+    pub fn find_solution(&self, day: u32) -> Option<&'static dyn DynSolution> {
+        self.solutions.iter().find(|s| s.day() == day).copied()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_answer() {
+        assert_eq!(Answer::from(42u32), Answer::from(42i64));
+        assert_ne!(Answer::from(-1i32), Answer::from(u128::MAX));
+        assert_ne!(Answer::from(42u8), Answer::from("42"));
+        assert_eq!(Answer::from(u64::MAX).to_string(), "18446744073709551615");
+        assert_eq!(Answer::from("4,6,3,5").to_string(), "4,6,3,5");
+    }
+
+    #[test]
+    fn test_checked() {
+        assert_eq!(checked::<usize, u32>(4_000_000_000), 4_000_000_000u32);
+        let overflow = std::panic::catch_unwind(|| checked::<u64, u32>(1 << 32));
+        assert!(overflow.is_err());
+    }
+}
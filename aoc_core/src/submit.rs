@@ -0,0 +1,274 @@
+use std::fmt;
+use std::fs::{create_dir_all, read_to_string, OpenOptions};
+use std::io::{ErrorKind, Write};
+
+use regex::Regex;
+use serde::{Deserialize, Serialize};
+
+use crate::config::{agent, Config, CONFIG_DIR};
+use crate::solution::Part;
+
+const GUESSES: &str = ".aoc/guesses.jsonl";
+
+/// What the site said about a submitted answer
+#[derive(Clone, PartialEq, Debug, Serialize, Deserialize)]
+pub enum Response {
+    Correct,
+    Wrong,
+    TooHigh,
+    TooLow,
+    /// Answered again before the cooldown ran out, with the time left to wait
+    TooSoon(String),
+    /// The part was solved already, or part 1 isn't solved yet
+    AlreadySolved,
+    Unrecognized(String),
+}
+
+impl Response {
+    /// Whether the answer was judged, only those are worth remembering
+    pub fn is_verdict(&self) -> bool {
+        matches!(
+            self,
+            Response::Correct | Response::Wrong | Response::TooHigh | Response::TooLow
+        )
+    }
+}
+
+impl fmt::Display for Response {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Response::Correct => write!(f, "correct"),
+            Response::Wrong => write!(f, "WRONG"),
+            Response::TooHigh => write!(f, "WRONG, too high"),
+            Response::TooLow => write!(f, "WRONG, too low"),
+            Response::TooSoon(wait) => write!(f, "answered too recently, wait {wait}"),
+            Response::AlreadySolved => write!(f, "not the current part, is it already solved?"),
+            Response::Unrecognized(text) => write!(f, "unrecognized response: {text}"),
+        }
+    }
+}
+
+/// Reads the verdict out of the page returned after submitting an answer
+pub fn parse_response(html: &str) -> Response {
+    if html.contains("That's the right answer") {
+        Response::Correct
+    } else if html.contains("your answer is too high") {
+        Response::TooHigh
+    } else if html.contains("your answer is too low") {
+        Response::TooLow
+    } else if html.contains("That's not the right answer") {
+        Response::Wrong
+    } else if html.contains("You gave an answer too recently") {
+        let wait = Regex::new(r"You have (.+?) left to wait")
+            .unwrap()
+            .captures(html)
+            .map_or("a bit".to_string(), |c| c[1].to_string());
+        Response::TooSoon(wait)
+    } else if html.contains("You don't seem to be solving the right level") {
+        Response::AlreadySolved
+    } else {
+        // The message is in the page's only <article>, drop the markup around it
+        let article = Regex::new(r"(?s)<article>(.*?)</article>")
+            .unwrap()
+            .captures(html)
+            .map_or(html.to_string(), |c| c[1].to_string());
+        let text = Regex::new(r"<[^>]*>").unwrap().replace_all(&article, "");
+        Response::Unrecognized(text.split_whitespace().collect::<Vec<_>>().join(" "))
+    }
+}
+
+/// Posts `answer` for one part of a day and returns the verdict
+pub fn post_answer(
+    config: &Config,
+    year: u32,
+    day: u32,
+    part: Part,
+    answer: &str,
+) -> Result<Response, String> {
+    let session = config.session()?;
+    let url = format!("{}/answer", config.day_url(year, day));
+    let level = part.number().to_string();
+    let mut response = agent()
+        .post(&url)
+        .header("Cookie", &format!("session={session}"))
+        .send_form([("level", level.as_str()), ("answer", answer)])
+        .map_err(|e| format!("Could not submit to {url}: {e}"))?;
+    if !response.status().is_success() {
+        return Err(format!("{url} returned {}", response.status()));
+    }
+    let html = response
+        .body_mut()
+        .read_to_string()
+        .map_err(|e| format!("Could not read the response from {url}: {e}"))?;
+    Ok(parse_response(&html))
+}
+
+/// One judged answer, as saved in the guess log
+#[derive(Clone, Serialize, Deserialize)]
+pub struct Guess {
+    pub year: u32,
+    pub day: u32,
+    pub part: u8,
+    pub answer: String,
+    pub response: Response,
+    pub date: String,
+}
+
+/// Every judged answer so far, kept in `.aoc/guesses.jsonl` so known wrong
+/// answers aren't submitted twice
+#[derive(Default)]
+pub struct GuessLog(Vec<Guess>);
+
+impl GuessLog {
+    pub fn load() -> Result<GuessLog, String> {
+        let text = match read_to_string(GUESSES) {
+            Ok(text) => text,
+            Err(e) if e.kind() == ErrorKind::NotFound => return Ok(GuessLog::default()),
+            Err(e) => return Err(format!("could not read {GUESSES}: {e}")),
+        };
+        text.lines()
+            .enumerate()
+            .filter(|(_, line)| !line.trim().is_empty())
+            .map(|(i, line)| {
+                serde_json::from_str(line).map_err(|e| format!("{GUESSES}:{}: {e}", i + 1))
+            })
+            .collect::<Result<_, _>>()
+            .map(GuessLog)
+    }
+
+    pub fn append(&mut self, guess: Guess) -> Result<(), String> {
+        create_dir_all(CONFIG_DIR).map_err(|e| e.to_string())?;
+        let mut file = OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(GUESSES)
+            .map_err(|e| e.to_string())?;
+        let line = serde_json::to_string(&guess).map_err(|e| e.to_string())?;
+        writeln!(file, "{line}").map_err(|e| e.to_string())?;
+        self.0.push(guess);
+        Ok(())
+    }
+
+    /// Explains why `answer` can't be right given the earlier guesses, if it can't
+    pub fn check(&self, year: u32, day: u32, part: Part, answer: &str) -> Result<(), String> {
+        let value = answer.parse::<i128>().ok();
+        for guess in self
+            .0
+            .iter()
+            .filter(|g| g.year == year && g.day == day && g.part == part.number())
+        {
+            let old = guess.answer.parse::<i128>().ok();
+            match guess.response {
+                Response::Correct if guess.answer == answer => {
+                    return Err(format!("{answer} was already accepted"))
+                }
+                Response::Correct => {
+                    return Err(format!("already solved, the answer was {}", guess.answer))
+                }
+                _ if guess.answer == answer => {
+                    return Err(format!("{answer} was already tried: {}", guess.response))
+                }
+                Response::TooHigh if value.zip(old).is_some_and(|(v, o)| v >= o) => {
+                    return Err(format!("{} was already too high", guess.answer))
+                }
+                Response::TooLow if value.zip(old).is_some_and(|(v, o)| v <= o) => {
+                    return Err(format!("{} was already too low", guess.answer))
+                }
+                _ => (),
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::mock_server::MockServer;
+
+    fn guess(answer: &str, response: Response) -> Guess {
+        Guess {
+            year: 2024,
+            day: 1,
+            part: 1,
+            answer: answer.to_string(),
+            response,
+            date: "2024-12-01T00:00:00Z".to_string(),
+        }
+    }
+
+    fn page(message: &str) -> String {
+        format!("<html><body><main>\n<article><p>{message}</p></article>\n</main></body></html>")
+    }
+
+    #[test]
+    fn test_parse_response() {
+        let cases = [
+            ("That's the right answer!  You are <span class=\"day-success\">one gold star</span> closer.", Response::Correct),
+            ("That's not the right answer; your answer is too high.  If you're stuck, ...", Response::TooHigh),
+            ("That's not the right answer; your answer is too low.", Response::TooLow),
+            ("That's not the right answer.  If you're stuck, make sure you're using the full input data", Response::Wrong),
+            ("You gave an answer too recently; you have to wait after submitting an answer before trying again.  You have 42s left to wait.", Response::TooSoon("42s".to_string())),
+            ("You don't seem to be solving the right level.  Did you already complete it?", Response::AlreadySolved),
+            ("Please <a href=\"/2024/auth/login\">log in</a> first.", Response::Unrecognized("Please log in first.".to_string())),
+        ];
+        for (message, expected) in cases {
+            assert_eq!(parse_response(&page(message)), expected);
+        }
+    }
+
+    #[test]
+    fn test_check() {
+        let log = GuessLog(vec![
+            guess("500", Response::TooHigh),
+            guess("100", Response::TooLow),
+            guess("250", Response::Wrong),
+        ]);
+        assert!(log.check(2024, 1, Part::One, "300").is_ok());
+        assert!(log.check(2024, 1, Part::One, "250").is_err());
+        assert!(log.check(2024, 1, Part::One, "500").is_err());
+        assert!(log.check(2024, 1, Part::One, "600").is_err());
+        assert!(log.check(2024, 1, Part::One, "100").is_err());
+        assert!(log.check(2024, 1, Part::One, "-3").is_err());
+        // Bounds only apply to the same part
+        assert!(log.check(2024, 1, Part::Two, "600").is_ok());
+
+        let log = GuessLog(vec![guess("abc", Response::Correct)]);
+        assert!(log.check(2024, 1, Part::One, "abd").is_err());
+        assert!(log.check(2024, 2, Part::One, "abd").is_ok());
+    }
+
+    #[test]
+    fn test_post_answer() {
+        let server = MockServer::start(vec![
+            (
+                200,
+                &page("That's not the right answer; your answer is too low."),
+            ),
+            (500, "oops"),
+        ]);
+        let config = Config {
+            base_url: server.url.clone(),
+            session: Some("secret".to_string()),
+        };
+        assert_eq!(
+            post_answer(&config, 2024, 7, Part::Two, "1234"),
+            Ok(Response::TooLow)
+        );
+        assert!(post_answer(&config, 2024, 7, Part::Two, "1234").is_err());
+
+        let requests = server.requests();
+        assert!(requests[0].starts_with("POST /2024/day/7/answer HTTP/1.1\r\n"));
+        assert!(requests[0].contains("session=secret"));
+        assert!(requests[0].ends_with("level=2&answer=1234"));
+    }
+
+    #[test]
+    fn test_post_answer_without_session() {
+        let config = Config {
+            session: None,
+            ..Config::default()
+        };
+        assert!(post_answer(&config, 2024, 7, Part::One, "1").is_err());
+    }
+}
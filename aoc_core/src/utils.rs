@@ -1,9 +1,12 @@
 use std::fmt;
+use std::fs::read_to_string;
 use std::ops::{Index, IndexMut};
 use std::path::Path;
 use std::slice::Iter;
 use std::vec::IntoIter;
 
+use serde::{Deserialize, Serialize};
+
 #[derive(Clone, Copy, PartialEq, Debug)]
 pub enum Direction {
     Up,
@@ -82,10 +85,7 @@ impl Direction {
     }
 
     pub fn is_vertical(&self) -> bool {
-        match self {
-            Direction::Up | Direction::Down => true,
-            _ => false,
-        }
+        matches!(self, Direction::Up | Direction::Down)
     }
 }
 
@@ -113,6 +113,10 @@ impl<T> Board<T> {
         self.board.len()
     }
 
+    pub fn is_empty(&self) -> bool {
+        self.board.is_empty()
+    }
+
     pub fn iter(&self) -> Iter<'_, Vec<T>> {
         self.board.iter()
     }
@@ -148,7 +152,7 @@ impl<T> Board<T> {
         // Guards against positive out of bounds
         if let Some(next_line) = self.board.get(next_i) {
             // Guards against positive out of bounds
-            if let Some(_) = next_line.get(next_j) {
+            if next_line.get(next_j).is_some() {
                 return Some((next_i, next_j));
             }
         };
@@ -180,43 +184,45 @@ impl<T> IndexMut<usize> for Board<T> {
     }
 }
 
-impl fmt::Display for Board<char> {
-    fn fmt(self: &Self, f: &mut fmt::Formatter) -> fmt::Result {
+/// A cell of a `Board` that can be drawn, which is all `Board` needs to be
+/// displayed. Days implement it for their own cell types.
+pub trait Tile {
+    fn to_char(&self) -> char;
+}
+
+impl<T: Tile> fmt::Display for Board<T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         writeln!(
             f,
             concat!("Board:\n\t{}"),
             self.board
                 .iter()
-                .map(|v| v.iter().collect())
+                .map(|v| v.iter().map(Tile::to_char).collect())
                 .collect::<Vec<String>>()
                 .join("\n\t"),
         )
     }
 }
 
-macro_rules! impl_board_display {
-    (for $($t:ty),+) => {
-        $(impl fmt::Display for Board<$t> {
-    fn fmt(self: &Self, f: &mut fmt::Formatter) -> fmt::Result {
-        writeln!(
-            f,
-            concat!("Board:\n\t{}"),
-            self.board
-                .iter()
-                .map(|v| v
-                    .iter()
-                    .map(|d| char::from_digit(d.clone().try_into().unwrap(), 10).expect("Type can't be converted into char from digit"))
-                    .collect::<String>())
-                .collect::<Vec<String>>()
-                .join("\n\t"),
-        )
+impl Tile for char {
+    fn to_char(&self) -> char {
+        *self
     }
+}
+
+macro_rules! impl_digit_tile {
+    (for $($t:ty),+) => {
+        $(impl Tile for $t {
+            fn to_char(&self) -> char {
+                char::from_digit((*self).try_into().unwrap(), 10)
+                    .expect("Type can't be converted into char from digit")
+            }
         })*
     }
 }
-impl_board_display!(for bool, u8, u16, u32, u64);
+impl_digit_tile!(for bool, u8, u16, u32, u64);
 
-#[derive(Debug)]
+#[derive(Debug, Clone, Copy, Serialize, Deserialize)]
 pub struct FileNotFound;
 impl fmt::Display for FileNotFound {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
@@ -227,11 +233,19 @@ impl fmt::Display for FileNotFound {
 pub const EXAMPLE: &str = "tests";
 pub const ACTUAL: &str = ".tests";
 
+pub fn test_file_path(path: &str, d: &str) -> String {
+    format!("./{path}/day{d}.txt")
+}
+
 pub fn get_test_file(path: &str, d: &str) -> Result<String, FileNotFound> {
-    let file_path = format!("./{path}/day{d}.txt");
+    let file_path = test_file_path(path, d);
     if Path::new(&file_path).exists() {
         Ok(file_path)
     } else {
         Err(FileNotFound)
     }
 }
+
+pub fn read_test_file(path: &str, d: &str) -> Result<String, FileNotFound> {
+    read_to_string(get_test_file(path, d)?).map_err(|_| FileNotFound)
+}
@@ -0,0 +1,31 @@
+use std::fs::metadata;
+use std::path::PathBuf;
+use std::thread::sleep;
+use std::time::{Duration, SystemTime};
+
+// How often the watched files are checked for changes
+const POLL_INTERVAL: Duration = Duration::from_millis(300);
+
+/// Clears the terminal and moves the cursor to the top
+pub fn clear_screen() {
+    print!("\x1B[2J\x1B[H");
+}
+
+// Modification time of each file, `None` while it doesn't exist
+fn modified(paths: &[PathBuf]) -> Vec<Option<SystemTime>> {
+    paths
+        .iter()
+        .map(|path| metadata(path).and_then(|m| m.modified()).ok())
+        .collect()
+}
+
+/// Blocks until one of `paths` is created, deleted or saved
+pub fn wait_for_change(paths: &[PathBuf]) {
+    let before = modified(paths);
+    loop {
+        sleep(POLL_INTERVAL);
+        if modified(paths) != before {
+            return;
+        }
+    }
+}
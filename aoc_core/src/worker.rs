@@ -0,0 +1,263 @@
+use std::env::current_exe;
+use std::io::{Read, Write};
+use std::process::{Child, Command, ExitStatus, Stdio};
+use std::thread::{self, sleep, JoinHandle};
+use std::time::{Duration, Instant};
+
+use crate::logging;
+use crate::runner::{failed_run, InputKind, InputRun, Outcome, PartRun, VariantRun};
+use crate::solution::{DynSolution, Part};
+
+// How often a running worker is checked on
+const POLL_INTERVAL: Duration = Duration::from_millis(5);
+
+/// Resources a single part may use before it is stopped
+#[derive(Clone, Copy)]
+pub struct Limits {
+    pub timeout: Option<Duration>,
+    pub memory_mb: Option<u64>,
+}
+
+impl Limits {
+    pub fn is_unlimited(&self) -> bool {
+        self.timeout.is_none() && self.memory_mb.is_none()
+    }
+}
+
+/// Caps the address space of the current process, so allocations past it fail
+#[cfg(unix)]
+pub fn set_memory_limit(mb: u64) -> Result<(), String> {
+    let bytes = mb.saturating_mul(1024 * 1024) as libc::rlim_t;
+    let limit = libc::rlimit {
+        rlim_cur: bytes,
+        rlim_max: bytes,
+    };
+    // SAFETY: `limit` is a valid rlimit that outlives the call
+    if unsafe { libc::setrlimit(libc::RLIMIT_AS, &limit) } == 0 {
+        Ok(())
+    } else {
+        Err(std::io::Error::last_os_error().to_string())
+    }
+}
+
+#[cfg(not(unix))]
+pub fn set_memory_limit(_mb: u64) -> Result<(), String> {
+    Err("memory limits are only supported on unix".to_string())
+}
+
+fn read_all(mut pipe: impl Read + Send + 'static) -> JoinHandle<String> {
+    thread::spawn(move || {
+        let mut buf = String::new();
+        let _ = pipe.read_to_string(&mut buf);
+        buf
+    })
+}
+
+// Waits for `child`, killing it once `timeout` has passed. Returns `None` on timeout.
+fn wait(child: &mut Child, timeout: Option<Duration>) -> Option<ExitStatus> {
+    let start = Instant::now();
+    loop {
+        match child.try_wait() {
+            Ok(Some(status)) => return Some(status),
+            Ok(None) => {}
+            Err(_) => return child.wait().ok(),
+        }
+        if timeout.is_some_and(|t| start.elapsed() >= t) {
+            let _ = child.kill();
+            let _ = child.wait();
+            return None;
+        }
+        sleep(POLL_INTERVAL);
+    }
+}
+
+// Runs one part in a worker process and returns what it reported. `text` is
+// fed to the worker's stdin when the input comes from stdin.
+fn run_part(
+    year: u32,
+    day: u32,
+    input: &InputKind,
+    text: Option<&str>,
+    part: Part,
+    variant: Option<&str>,
+    limits: Limits,
+) -> Result<InputRun, Outcome> {
+    let exe = current_exe().map_err(|e| Outcome::Panicked(e.to_string()))?;
+    let mut command = Command::new(exe);
+    command
+        .args(["--year", &year.to_string()])
+        // The worker starts in the year's folder already
+        .args(["--dir", "."])
+        .arg("worker")
+        .args(["--day", &day.to_string()])
+        .args(["--input", &input.to_arg()])
+        .args(["--part", &part.number().to_string()]);
+    if text.is_some() {
+        command.arg("--text");
+    }
+    if let Some(variant) = variant {
+        command.args(["--variant", variant]);
+    }
+    if let Some(mb) = limits.memory_mb {
+        command.args(["--memory-limit", &mb.to_string()]);
+    }
+    command.args(logging::worker_args());
+    let mut child = command
+        .stdin(if text.is_some() {
+            Stdio::piped()
+        } else {
+            Stdio::null()
+        })
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .spawn()
+        .map_err(|e| Outcome::Panicked(format!("could not start worker: {e}")))?;
+
+    if let (Some(text), Some(mut pipe)) = (text, child.stdin.take()) {
+        let text = text.to_string();
+        // Written from a thread so a worker that never reads can still time out
+        thread::spawn(move || pipe.write_all(text.as_bytes()));
+    }
+    let stdout = read_all(child.stdout.take().expect("stdout is piped"));
+    let stderr = read_all(child.stderr.take().expect("stderr is piped"));
+    let status = wait(&mut child, limits.timeout);
+    let stdout = stdout.join().unwrap_or_default();
+    let stderr = stderr.join().unwrap_or_default();
+    if logging::is_enabled() {
+        eprint!("{stderr}");
+    }
+
+    match status {
+        None => Err(Outcome::TimedOut(limits.timeout.unwrap_or_default())),
+        Some(status) if status.success() => serde_json::from_str(&stdout)
+            .map_err(|e| Outcome::Panicked(format!("bad worker output: {e}"))),
+        // Rust aborts with this message when an allocation fails
+        Some(_) if stderr.contains("memory allocation of") => Err(Outcome::OutOfMemory),
+        Some(status) => Err(Outcome::Panicked(format!(
+            "worker failed ({status}): {}",
+            stderr.lines().last().unwrap_or_default()
+        ))),
+    }
+}
+
+/// Same as `runner::run_input`, but every part runs in its own process that
+/// is stopped once it goes over `limits`. Each worker parses the input itself,
+/// the reported parse time is the first one.
+pub fn run_input_isolated(
+    year: u32,
+    solution: &dyn DynSolution,
+    input: &InputKind,
+    parts: &[Part],
+    variant: Option<&str>,
+    limits: Limits,
+) -> InputRun {
+    let day = solution.day();
+    // Stdin can only be read once, so it is read here and handed to each worker
+    let text = match input {
+        InputKind::Stdin => match input.read(day) {
+            Ok(text) => Some(text),
+            Err(err) => return failed_run(day, input, parts, Outcome::Missing(err)),
+        },
+        _ => None,
+    };
+    run_isolated(
+        year,
+        solution,
+        input,
+        text.as_deref(),
+        parts,
+        variant,
+        limits,
+    )
+}
+
+/// Same as `runner::run_text`, but isolated like `run_input_isolated`
+pub fn run_text_isolated(
+    year: u32,
+    solution: &dyn DynSolution,
+    input: &InputKind,
+    text: &str,
+    parts: &[Part],
+    variant: Option<&str>,
+    limits: Limits,
+) -> InputRun {
+    run_isolated(year, solution, input, Some(text), parts, variant, limits)
+}
+
+/// Same as `runner::cross_check`, but every variant runs in its own worker
+/// like `run_input_isolated`, and parses the input itself
+pub fn cross_check_isolated(
+    year: u32,
+    solution: &dyn DynSolution,
+    input: &InputKind,
+    parts: &[Part],
+    limits: Limits,
+) -> Result<Vec<(Part, Vec<VariantRun>)>, Outcome> {
+    // Read once, since stdin can't be read again for the next variant
+    let text = input.read(solution.day()).map_err(Outcome::Missing)?;
+    let mut results = vec![];
+    for part in parts {
+        let variants = solution.variants(*part);
+        if variants.len() < 2 {
+            results.push((*part, vec![]));
+            continue;
+        }
+        let mut runs = vec![];
+        for name in variants {
+            let run = run_text_isolated(year, solution, input, &text, &[*part], Some(name), limits);
+            // A variant that fails, parsing included, fails its own row
+            let part_run = run
+                .parts
+                .into_iter()
+                .next()
+                .expect("one part is always run");
+            runs.push(VariantRun {
+                name,
+                outcome: part_run.outcome,
+                elapsed: part_run.elapsed,
+            });
+        }
+        results.push((*part, runs));
+    }
+    Ok(results)
+}
+
+fn run_isolated(
+    year: u32,
+    solution: &dyn DynSolution,
+    input: &InputKind,
+    text: Option<&str>,
+    parts: &[Part],
+    variant: Option<&str>,
+    limits: Limits,
+) -> InputRun {
+    let day = solution.day();
+    let mut parse: Option<Result<Duration, Outcome>> = None;
+    let mut part_runs: Vec<PartRun> = vec![];
+    for part in parts {
+        match run_part(year, day, input, text, *part, variant, limits) {
+            Ok(run) => {
+                if let Err(outcome) = run.parse {
+                    // Parsing fails the same way for every part
+                    return failed_run(day, input, parts, outcome);
+                }
+                parse.get_or_insert(run.parse);
+                part_runs.extend(run.parts);
+            }
+            Err(outcome) => part_runs.push(PartRun::failed(*part, outcome)),
+        }
+    }
+
+    let parse = parse.unwrap_or_else(|| {
+        Err(part_runs
+            .first()
+            .map(|p| p.outcome.clone())
+            .unwrap_or(Outcome::Panicked("nothing to run".to_string())))
+    });
+    InputRun {
+        day,
+        input: input.clone(),
+        parse,
+        parts: part_runs,
+    }
+}
@@ -0,0 +1,153 @@
+//! Python bindings for the solutions and the grid helpers in `utils`, built
+//! as the `aoc_py` extension module with maturin:
+//!
+//! ```sh
+//! cd aoc_py && maturin develop --release
+//! python tests/test_aoc_py.py
+//! ```
+
+use std::panic::{catch_unwind, AssertUnwindSafe};
+
+use pyo3::exceptions::{PyIndexError, PyValueError};
+use pyo3::prelude::*;
+
+use aoc_core::solution::{Part, Year};
+use aoc_core::utils::{Board, Direction};
+
+const YEAR: Year = advent_of_code_24::YEAR;
+
+/// Solves `part` (1 or 2) of `day` for the puzzle input `text` and returns the
+/// answer as a string. `example=True` parses `text` as the day's example, for
+/// days that solve that with other settings. Raises `ValueError` for an unknown
+/// day or part, and for an input the day fails on.
+#[pyfunction]
+#[pyo3(signature = (day, part, text, example = false))]
+fn solve(py: Python<'_>, day: u32, part: u8, text: &str, example: bool) -> PyResult<String> {
+    let part = match part {
+        1 => Part::One,
+        2 => Part::Two,
+        _ => {
+            return Err(PyValueError::new_err(format!(
+                "There is no part {part}, only 1 and 2"
+            )))
+        }
+    };
+    let solution = YEAR.find_solution(day).ok_or_else(|| {
+        PyValueError::new_err(format!("Day {day} of {} is not implemented", YEAR.year))
+    })?;
+    // The GIL isn't needed while solving, so other Python threads keep running
+    py.detach(|| {
+        catch_unwind(AssertUnwindSafe(|| {
+            let parsed = solution.parse(text, example);
+            solution.solve(parsed.as_ref(), part, None).to_string()
+        }))
+    })
+    .map_err(|payload| {
+        let msg = match payload.downcast_ref::<&str>() {
+            Some(msg) => msg.to_string(),
+            None => payload
+                .downcast_ref::<String>()
+                .cloned()
+                .unwrap_or_default(),
+        };
+        PyValueError::new_err(format!("Day {day} failed: {msg}"))
+    })
+}
+
+/// `utils::Direction`, for moving around a `Board`
+#[pyclass(name = "Direction", eq, eq_int, frozen, from_py_object)]
+#[derive(Clone, Copy, PartialEq)]
+enum PyDirection {
+    Up,
+    Right,
+    Down,
+    Left,
+    UpRight,
+    DownRight,
+    DownLeft,
+    UpLeft,
+}
+
+impl From<PyDirection> for Direction {
+    fn from(direction: PyDirection) -> Self {
+        match direction {
+            PyDirection::Up => Direction::Up,
+            PyDirection::Right => Direction::Right,
+            PyDirection::Down => Direction::Down,
+            PyDirection::Left => Direction::Left,
+            PyDirection::UpRight => Direction::UpRight,
+            PyDirection::DownRight => Direction::DownRight,
+            PyDirection::DownLeft => Direction::DownLeft,
+            PyDirection::UpLeft => Direction::UpLeft,
+        }
+    }
+}
+
+#[pymethods]
+impl PyDirection {
+    /// The `(row, column)` step this direction takes
+    fn get_direction(&self) -> (isize, isize) {
+        Direction::from(*self).get_direction()
+    }
+}
+
+/// A `utils::Board` of characters, one row per line of the text it is made from.
+/// Positions are `(row, column)` tuples.
+#[pyclass(name = "Board")]
+struct PyBoard(Board<char>);
+
+#[pymethods]
+impl PyBoard {
+    #[new]
+    fn new(text: &str) -> Self {
+        PyBoard(Board::new(
+            text.lines().map(|line| line.chars().collect()).collect(),
+        ))
+    }
+
+    /// The position one step from `pos` in `direction`, or `None` when it
+    /// would leave the board
+    fn add_direction(&self, direction: PyDirection, pos: (usize, usize)) -> Option<(usize, usize)> {
+        self.0.add_direction(&direction.into(), pos)
+    }
+
+    /// The character at `pos`, or `None` when it is outside the board
+    fn get_pos(&self, pos: (usize, usize)) -> Option<char> {
+        if pos.0 >= self.0.len() {
+            return None;
+        }
+        self.0.get_pos(pos).copied()
+    }
+
+    fn update_pos(&mut self, pos: (usize, usize), value: char) -> PyResult<()> {
+        if self.get_pos(pos).is_none() {
+            return Err(PyIndexError::new_err(format!(
+                "{pos:?} is outside the board"
+            )));
+        }
+        self.0.update_pos(pos, value);
+        Ok(())
+    }
+
+    fn __len__(&self) -> usize {
+        self.0.len()
+    }
+
+    /// The board as `Board` prints it in Rust
+    fn __str__(&self) -> String {
+        self.0.to_string()
+    }
+
+    fn __repr__(&self) -> String {
+        let rows: Vec<String> = self.0.iter().map(|row| row.iter().collect()).collect();
+        format!("Board({:?})", rows.join("\n"))
+    }
+}
+
+#[pymodule]
+fn aoc_py(m: &Bound<'_, PyModule>) -> PyResult<()> {
+    m.add_function(wrap_pyfunction!(solve, m)?)?;
+    m.add_class::<PyBoard>()?;
+    m.add_class::<PyDirection>()?;
+    Ok(())
+}
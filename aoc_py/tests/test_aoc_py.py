@@ -0,0 +1,66 @@
+"""Checks the aoc_py bindings against the examples of advent_of_code_24.
+
+Run after `maturin develop` in aoc_py/: python tests/test_aoc_py.py
+"""
+
+import unittest
+from pathlib import Path
+
+import aoc_py
+from aoc_py import Board, Direction
+
+EXAMPLES = Path(__file__).resolve().parents[2] / "advent_of_code_24" / "tests"
+
+
+def example(day):
+    return (EXAMPLES / f"day{day:02}.txt").read_text()
+
+
+class SolveTest(unittest.TestCase):
+    def test_examples(self):
+        self.assertEqual(aoc_py.solve(1, 1, example(1)), "11")
+        self.assertEqual(aoc_py.solve(1, 2, example(1)), "31")
+        self.assertEqual(aoc_py.solve(11, 2, example(11)), "65601038650482")
+        self.assertEqual(aoc_py.solve(14, 1, example(14), example=True), "12")
+
+    def test_errors(self):
+        with self.assertRaises(ValueError):
+            aoc_py.solve(25, 1, "")
+        with self.assertRaises(ValueError):
+            aoc_py.solve(1, 3, example(1))
+        with self.assertRaises(ValueError):
+            aoc_py.solve(5, 1, "not an input")
+        with self.assertRaises(ValueError):
+            aoc_py.solve(14, 1, example(14))
+
+
+class BoardTest(unittest.TestCase):
+    def setUp(self):
+        self.board = Board("..#\n.X.\n")
+
+    def test_get_pos(self):
+        self.assertEqual(len(self.board), 2)
+        self.assertEqual(self.board.get_pos((0, 2)), "#")
+        self.assertIsNone(self.board.get_pos((0, 3)))
+        self.assertIsNone(self.board.get_pos((2, 0)))
+
+    def test_add_direction(self):
+        self.assertEqual(self.board.add_direction(Direction.Up, (1, 1)), (0, 1))
+        self.assertEqual(self.board.add_direction(Direction.UpRight, (1, 1)), (0, 2))
+        self.assertIsNone(self.board.add_direction(Direction.Up, (0, 1)))
+        self.assertIsNone(self.board.add_direction(Direction.Right, (1, 2)))
+        self.assertEqual(Direction.Left.get_direction(), (0, -1))
+
+    def test_update_pos(self):
+        self.board.update_pos((0, 0), "@")
+        self.assertEqual(self.board.get_pos((0, 0)), "@")
+        with self.assertRaises(IndexError):
+            self.board.update_pos((5, 5), "@")
+
+    def test_render(self):
+        self.assertEqual(str(self.board), "Board:\n\t..#\n\t.X.\n")
+        self.assertEqual(repr(self.board), 'Board("..#\\n.X.")')
+
+
+if __name__ == "__main__":
+    unittest.main()
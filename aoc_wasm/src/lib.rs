@@ -0,0 +1,51 @@
+//! The solutions built for `wasm32-unknown-unknown`, so they can run in a
+//! browser page. Only the solvers are compiled in, without the `aoc` runner:
+//!
+//! ```sh
+//! cargo build -p aoc_wasm --release --target wasm32-unknown-unknown
+//! wasm-bindgen --target web --out-dir aoc_wasm/pkg \
+//!     target/wasm32-unknown-unknown/release/aoc_wasm.wasm
+//! ```
+//!
+//! `aoc_wasm/index.html` then calls [`solve`] from the generated `pkg/aoc_wasm.js`.
+
+use wasm_bindgen::prelude::*;
+
+use aoc_core::solution::{Part, Year};
+
+const YEAR: Year = advent_of_code_24::YEAR;
+
+/// Parses `input` with `day`'s parser and solves `part` (1 or 2) of it,
+/// returning the answer as text. `example` parses it as the day's example, for
+/// days that solve that with other settings. An unknown day or part is thrown
+/// as an error.
+///
+/// Panics can't be caught in wasm, so an input the day can't parse aborts the
+/// call with a `RuntimeError: unreachable` instead.
+#[wasm_bindgen]
+pub fn solve(day: u32, part: u8, input: &str, example: bool) -> Result<String, String> {
+    let part = match part {
+        1 => Part::One,
+        2 => Part::Two,
+        _ => return Err(format!("There is no part {part}, only 1 and 2")),
+    };
+    let solution = YEAR
+        .find_solution(day)
+        .ok_or(format!("Day {day} of {} is not implemented", YEAR.year))?;
+    let parsed = solution.parse(input, example);
+    Ok(solution.solve(parsed.as_ref(), part, None).to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_solve() {
+        let input = "3   4\n4   3\n2   5\n1   3\n3   9\n3   3\n";
+        assert_eq!(solve(1, 1, input, true), Ok("11".to_string()));
+        assert_eq!(solve(1, 2, input, false), Ok("31".to_string()));
+        assert!(solve(1, 3, input, true).is_err());
+        assert!(solve(25, 1, input, true).is_err());
+    }
+}